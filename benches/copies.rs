@@ -32,11 +32,7 @@ use std::collections::HashMap;
 use std::ptr;
 
 fn seq<const N: usize>(size: usize) -> Vec<[usize; N]> {
-    let mut v = vec![[0; N]; size];
-    for i in 0..size {
-        v[i] = [i + 1; N];
-    }
-    v
+    (1..=size).map(|i| [i; N]).collect()
 }
 
 enum Fun {
@@ -154,7 +150,7 @@ fn case_copy<const N: usize>(
 
     let max_distance = distances.iter().map(|d| d.unsigned_abs()).max().unwrap();
     let mut v = seq::<N>(len + max_distance);
-    let start = *&v[..].as_mut_ptr();
+    let start = v.as_mut_ptr();
 
     for d in distances {
         for fun in &funs {
@@ -190,7 +186,7 @@ fn case_shift_left<const N: usize>(c: &mut Criterion, lens: &[usize]) {
     let max_len = *lens.iter().max().unwrap();
     let mut g = c.benchmark_group(format!("Shift left/{max_len}/{N}"));
     let mut v = seq::<N>(max_len + 1);
-    let start = *&v[..].as_mut_ptr();
+    let start = v.as_mut_ptr();
 
     for len in lens {
         for fun in &funs {
@@ -223,7 +219,7 @@ fn case_shift_right<const N: usize>(c: &mut Criterion, lens: &[usize]) {
     let max_len = *lens.iter().max().unwrap();
     let mut g = c.benchmark_group(format!("Shift right/{max_len}/{N}"));
     let mut v = seq::<N>(max_len + 1);
-    let start = *&v[..].as_mut_ptr();
+    let start = v.as_mut_ptr();
 
     for len in lens {
         for fun in &funs {
@@ -269,7 +265,7 @@ fn bench_copy_nonoverlapping(c: &mut Criterion) {
         (
             100_000,
             [
-                100_000, 150_000, 200_000, 300_000, 500_000, 1000_000, 1_500_000,
+                100_000, 150_000, 200_000, 300_000, 500_000, 1_000_000, 1_500_000,
             ],
         ),
     ]);
@@ -307,7 +303,7 @@ fn case_copy_nonoverlapping_by_len<const N: usize>(c: &mut Criterion, lens: &[us
     let mut g = c.benchmark_group(format!("Copy nonoverlapping by len/{max_len}/{N}"));
 
     let mut v = seq::<N>(2 * max_len + 1000);
-    let start = *&v[..].as_mut_ptr();
+    let start = v.as_mut_ptr();
 
     for len in lens {
         let d = (len + 1000) as isize;
@@ -498,12 +494,53 @@ fn bench_shift_right(c: &mut Criterion) {
     case_shift_right::<320>(c, &lens_100_000);
 }
 
+fn case_copy_bytes<const S: usize>(c: &mut Criterion, len: usize, distances: &[isize]) {
+    let mut g = c.benchmark_group(format!("Copy bytes/{len}/{S}"));
+
+    let max_distance = distances.iter().map(|d| d.unsigned_abs()).max().unwrap();
+    let mut v: Vec<[u8; S]> = (0..len + max_distance).map(|i| [i as u8; S]).collect();
+    let start = v.as_mut_ptr();
+
+    for &d in distances {
+        let s = unsafe { start.add(if d < 0 { d.unsigned_abs() } else { 0 }) };
+
+        g.bench_with_input(BenchmarkId::new("utils::copy", d), &d, |b, _| {
+            b.iter(|| unsafe { copy(s, s.offset(d), len) })
+        });
+
+        g.bench_with_input(BenchmarkId::new("utils::byte_copy", d), &d, |b, _| {
+            b.iter(|| unsafe { byte_copy(s, s.offset(d), len) })
+        });
+
+        g.bench_with_input(BenchmarkId::new("utils::copy_smart", d), &d, |b, _| {
+            b.iter(|| unsafe { copy_smart(s, s.offset(d), len) })
+        });
+    }
+
+    g.finish();
+}
+
+/// cargo bench --bench=copies "Copy bytes"
+fn bench_copy_bytes(c: &mut Criterion) {
+    let distances = [-100, -1, 1, 100];
+
+    case_copy_bytes::<1>(c, 1000, &distances);
+    case_copy_bytes::<3>(c, 1000, &distances);
+    case_copy_bytes::<5>(c, 1000, &distances);
+    case_copy_bytes::<8>(c, 1000, &distances);
+    case_copy_bytes::<12>(c, 1000, &distances);
+    case_copy_bytes::<16>(c, 1000, &distances);
+    case_copy_bytes::<20>(c, 1000, &distances);
+    case_copy_bytes::<24>(c, 1000, &distances);
+    case_copy_bytes::<36>(c, 1000, &distances);
+}
+
 criterion_group! {
     name = benches;
 
     config = Criterion::default();
 
-    targets = bench_copy, bench_copy_nonoverlapping, bench_copy_nonoverlapping_by_len, bench_shift_left, bench_shift_right
+    targets = bench_copy, bench_copy_nonoverlapping, bench_copy_nonoverlapping_by_len, bench_shift_left, bench_shift_right, bench_copy_bytes
 }
 
 criterion_main!(benches);
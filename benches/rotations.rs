@@ -7,11 +7,7 @@ use rust_rotations::*;
 use std::cmp;
 
 fn seq<const N: usize>(size: usize) -> Vec<[usize; N]> {
-    let mut v = vec![[0; N]; size];
-    for i in 0..size {
-        v[i] = [i + 1; N];
-    }
-    v
+    (1..=size).map(|i| [i; N]).collect()
 }
 
 fn test<T>(
@@ -33,22 +29,35 @@ fn buf_test<T>(
     unsafe { rotate(left, p, right, buffer) }
 }
 
+#[allow(dead_code)]
 enum Rotation {
     Direct,
+    Algo1,
+    Juggling,
     Aux,
+    AuxStack,
     NaiveAux,
     Bridge,
+    BridgeSimple,
     Contrev,
+    ContrevV2,
     ContrevB,
+    ContrevBuf,
     Piston,
     GM,
     GMRec,
+    GMOpt,
+    Grail,
+    Swap,
     Helix,
     Drill,
     Edge,
+    Tiny,
     Stable,
     Rev,
+    RevRec,
     RevB,
+    Comb,
 }
 
 fn case<const N: usize>(
@@ -60,14 +69,11 @@ fn case<const N: usize>(
 ) {
     let mut group = c.benchmark_group(format!("{name}/{len}/{N}"));
 
-    let mut buffer = Vec::<[usize; N]>::with_capacity(len);
+    let mut buffer = seq::<N>(len);
     let mut v = seq::<N>(len);
 
     for l in lefts {
-        let mid = unsafe {
-            let p = &v[..].as_mut_ptr().add(l.clone());
-            p.clone()
-        };
+        let mid = unsafe { v.as_mut_ptr().add(*l) };
 
         let r = len - l;
 
@@ -77,32 +83,77 @@ fn case<const N: usize>(
             match rotation {
                 Direct => {
                     group.bench_with_input(BenchmarkId::new("Direct", l), l, |b, _| {
-                        b.iter(|| test(ptr_direct_rotate::<[usize; N]>, l.clone(), mid, r))
+                        b.iter(|| test(ptr_direct_rotate::<[usize; N]>, *l, mid, r))
+                    });
+                }
+                Algo1 => {
+                    group.bench_with_input(BenchmarkId::new("Algo1", l), l, |b, _| {
+                        b.iter(|| test(ptr_algo1_rotate::<[usize; N]>, *l, mid, r))
+                    });
+                }
+                Juggling => {
+                    group.bench_with_input(BenchmarkId::new("Juggling", l), l, |b, _| {
+                        b.iter(|| test(ptr_juggling_rotate::<[usize; N]>, *l, mid, r))
                     });
                 }
                 Contrev => {
                     group.bench_with_input(BenchmarkId::new("Contrev", l), l, |b, _| {
-                        b.iter(|| test(ptr_contrev_rotate::<[usize; N]>, l.clone(), mid, r))
+                        b.iter(|| test(ptr_contrev_rotate::<[usize; N]>, *l, mid, r))
+                    });
+                }
+                ContrevV2 => {
+                    group.bench_with_input(BenchmarkId::new("Contrev (v2)", l), l, |b, _| {
+                        b.iter(|| test(ptr_contrev_rotate_v2::<[usize; N]>, *l, mid, r))
                     });
                 }
                 ContrevB => {
                     group.bench_with_input(BenchmarkId::new("ContrevB", l), l, |b, _| {
-                        b.iter(|| test(ptr_block_contrev_rotate::<[usize; N]>, l.clone(), mid, r))
+                        b.iter(|| test(ptr_block_contrev_rotate::<[usize; N]>, *l, mid, r))
+                    });
+                }
+                ContrevBuf => {
+                    let mut scratch = seq::<N>(32);
+
+                    group.bench_with_input(BenchmarkId::new("Contrev (buf)", l), l, |b, _| {
+                        b.iter(|| {
+                            buf_test(
+                                ptr_contrev_rotate_buffered::<[usize; N]>,
+                                *l,
+                                mid,
+                                r,
+                                scratch.as_mut_slice(),
+                            )
+                        })
                     });
                 }
                 GM => {
                     group.bench_with_input(BenchmarkId::new("GM", l), l, |b, _| {
-                        b.iter(|| test(ptr_griesmills_rotate::<[usize; N]>, l.clone(), mid, r))
+                        b.iter(|| test(ptr_griesmills_rotate::<[usize; N]>, *l, mid, r))
                     });
                 }
                 GMRec => {
                     group.bench_with_input(BenchmarkId::new("GM (rec)", l), l, |b, _| {
-                        b.iter(|| test(ptr_griesmills_rotate_rec::<[usize; N]>, l.clone(), mid, r))
+                        b.iter(|| test(ptr_griesmills_rotate_rec::<[usize; N]>, *l, mid, r))
+                    });
+                }
+                GMOpt => {
+                    group.bench_with_input(BenchmarkId::new("GM (opt)", l), l, |b, _| {
+                        b.iter(|| test(ptr_griesmills_rotate_opt::<[usize; N]>, *l, mid, r))
+                    });
+                }
+                Grail => {
+                    group.bench_with_input(BenchmarkId::new("Grail", l), l, |b, _| {
+                        b.iter(|| test(ptr_grail_rotate::<[usize; N]>, *l, mid, r))
+                    });
+                }
+                Swap => {
+                    group.bench_with_input(BenchmarkId::new("Swap", l), l, |b, _| {
+                        b.iter(|| test(ptr_swap_rotate::<[usize; N]>, *l, mid, r))
                     });
                 }
                 Helix => {
                     group.bench_with_input(BenchmarkId::new("Helix", l), l, |b, _| {
-                        b.iter(|| test(ptr_helix_rotate::<[usize; N]>, l.clone(), mid, r))
+                        b.iter(|| test(ptr_helix_rotate::<[usize; N]>, *l, mid, r))
                     });
                 }
                 Aux => {
@@ -110,7 +161,7 @@ fn case<const N: usize>(
                         b.iter(|| {
                             buf_test(
                                 ptr_aux_rotate::<[usize; N]>,
-                                l.clone(),
+                                *l,
                                 mid,
                                 r,
                                 buffer.as_mut_slice(),
@@ -118,12 +169,17 @@ fn case<const N: usize>(
                         })
                     });
                 }
+                AuxStack => {
+                    group.bench_with_input(BenchmarkId::new("Aux (stack)", l), l, |b, _| {
+                        b.iter(|| test(ptr_aux_rotate_stack::<[usize; N]>, *l, mid, r))
+                    });
+                }
                 NaiveAux => {
                     group.bench_with_input(BenchmarkId::new("Aux (naive)", l), l, |b, _| {
                         b.iter(|| {
                             buf_test(
                                 ptr_naive_aux_rotate::<[usize; N]>,
-                                l.clone(),
+                                *l,
                                 mid,
                                 r,
                                 buffer.as_mut_slice(),
@@ -139,7 +195,7 @@ fn case<const N: usize>(
                             b.iter(|| {
                                 buf_test(
                                     ptr_bridge_rotate::<[usize; N]>,
-                                    l.clone(),
+                                    *l,
                                     mid,
                                     r,
                                     buffer.as_mut_slice(),
@@ -148,34 +204,77 @@ fn case<const N: usize>(
                         });
                     };
                 }
+                BridgeSimple => {
+                    let bridge = l.abs_diff(r);
+
+                    if cmp::min(l, &r) > &bridge {
+                        group.bench_with_input(
+                            BenchmarkId::new("Bridge (simple)", l),
+                            l,
+                            |b, _| {
+                                b.iter(|| {
+                                    buf_test(
+                                        |l, mid, r, buf| unsafe {
+                                            ptr_bridge_rotate_simple::<[usize; N]>(
+                                                l,
+                                                mid,
+                                                r,
+                                                buf.as_mut_ptr(),
+                                            )
+                                        },
+                                        *l,
+                                        mid,
+                                        r,
+                                        buffer.as_mut_slice(),
+                                    )
+                                })
+                            },
+                        );
+                    };
+                }
                 Rev => {
                     group.bench_with_input(BenchmarkId::new("Rev", l), l, |b, _| {
-                        b.iter(|| test(ptr_reversal_rotate::<[usize; N]>, l.clone(), mid, r))
+                        b.iter(|| test(ptr_reversal_rotate::<[usize; N]>, *l, mid, r))
+                    });
+                }
+                RevRec => {
+                    group.bench_with_input(BenchmarkId::new("Rev (rec)", l), l, |b, _| {
+                        b.iter(|| test(ptr_reversal_rotate_rec::<[usize; N]>, *l, mid, r))
                     });
                 }
                 RevB => {
                     group.bench_with_input(BenchmarkId::new("RevB", l), l, |b, _| {
-                        b.iter(|| test(ptr_block_reversal_rotate::<[usize; N]>, l.clone(), mid, r))
+                        b.iter(|| test(ptr_block_reversal_rotate::<[usize; N]>, *l, mid, r))
+                    });
+                }
+                Comb => {
+                    group.bench_with_input(BenchmarkId::new("Comb", l), l, |b, _| {
+                        b.iter(|| test(ptr_comb_rotate::<[usize; N]>, *l, mid, r))
                     });
                 }
                 Piston => {
                     group.bench_with_input(BenchmarkId::new("Piston", l), l, |b, _| {
-                        b.iter(|| test(ptr_piston_rotate::<[usize; N]>, l.clone(), mid, r))
+                        b.iter(|| test(ptr_piston_rotate::<[usize; N]>, *l, mid, r))
                     });
                 }
                 Drill => {
                     group.bench_with_input(BenchmarkId::new("Drill", l), l, |b, _| {
-                        b.iter(|| test(ptr_drill_rotate::<[usize; N]>, l.clone(), mid, r))
+                        b.iter(|| test(ptr_drill_rotate::<[usize; N]>, *l, mid, r))
                     });
                 }
                 Edge => {
                     group.bench_with_input(BenchmarkId::new("Edge", l), l, |b, _| {
-                        b.iter(|| test(ptr_edge_rotate::<[usize; N]>, l.clone(), mid, r))
+                        b.iter(|| test(ptr_edge_rotate::<[usize; N]>, *l, mid, r))
+                    });
+                }
+                Tiny => {
+                    group.bench_with_input(BenchmarkId::new("Tiny", l), l, |b, _| {
+                        b.iter(|| test(ptr_tiny_rotate::<[usize; N]>, *l, mid, r))
                     });
                 }
                 Stable => {
                     group.bench_with_input(BenchmarkId::new("Stable", l), l, |b, _| {
-                        b.iter(|| test(stable_ptr_rotate::<[usize; N]>, l.clone(), mid, r))
+                        b.iter(|| test(stable_ptr_rotate::<[usize; N]>, *l, mid, r))
                     });
                 }
             }
@@ -187,13 +286,34 @@ fn case<const N: usize>(
 fn case_buf<const N: usize>(c: &mut Criterion, length: usize, ls: &[usize]) {
     use Rotation::*;
 
-    case::<N>("Buf", c, length, ls, vec![Direct, NaiveAux, Aux, Bridge]);
+    case::<N>(
+        "Buf",
+        c,
+        length,
+        ls,
+        vec![
+            Direct,
+            Algo1,
+            Juggling,
+            NaiveAux,
+            Aux,
+            AuxStack,
+            Bridge,
+            BridgeSimple,
+        ],
+    );
 }
 
 fn case_rev<const N: usize>(c: &mut Criterion, length: usize, ls: &[usize]) {
     use Rotation::*;
 
-    case::<N>("Rev", c, length, ls, vec![Direct, Rev, RevB, Bridge, Aux]);
+    case::<N>(
+        "Rev",
+        c,
+        length,
+        ls,
+        vec![Direct, Rev, RevRec, RevB, Comb, Bridge, Aux],
+    );
 }
 
 fn case_contrev<const N: usize>(c: &mut Criterion, length: usize, ls: &[usize]) {
@@ -204,14 +324,20 @@ fn case_contrev<const N: usize>(c: &mut Criterion, length: usize, ls: &[usize])
         c,
         length,
         ls,
-        vec![Direct, Contrev, ContrevB, Bridge, Aux],
+        vec![Direct, Contrev, ContrevB, ContrevBuf, Bridge, Aux],
     );
 }
 
 fn case_gm<const N: usize>(c: &mut Criterion, length: usize, ls: &[usize]) {
     use Rotation::*;
 
-    case::<N>("GM", c, length, ls, vec![Direct, GM, GMRec, Drill]);
+    case::<N>(
+        "GM",
+        c,
+        length,
+        ls,
+        vec![Direct, GM, GMRec, GMOpt, Grail, Drill, Swap, Piston],
+    );
 }
 
 fn case_main<const N: usize>(c: &mut Criterion, length: usize, ls: &[usize]) {
@@ -260,6 +386,31 @@ fn bench_short(c: &mut Criterion) {
     }
 }
 
+fn case_tiny<const N: usize>(c: &mut Criterion, length: usize) {
+    use Rotation::*;
+
+    let ls: Vec<usize> = (0..=length).collect();
+
+    case::<N>(
+        "Tiny",
+        c,
+        length,
+        &ls,
+        vec![Tiny, Edge, Stable, Direct, Rev],
+    );
+}
+
+/// cargo bench --bench=rotations "Tiny"
+fn bench_tiny(c: &mut Criterion) {
+    for l in 2..=8 {
+        case_tiny::<1>(c, l);
+    }
+
+    for l in 2..=8 {
+        case_tiny::<4>(c, l);
+    }
+}
+
 fn bench_buf(c: &mut Criterion) {
     // 1 * usize
     case_buf::<1>(c, 15, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14]);
@@ -357,6 +508,458 @@ fn bench_contrev(c: &mut Criterion) {
     );
 }
 
+fn bench_contrev_buf(c: &mut Criterion) {
+    // 10 * usize
+    case_contrev::<10>(c, 15, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14]);
+    case_contrev::<10>(c, 100, &[1, 6, 20, 30, 40, 48, 53, 60, 70, 80, 90, 95, 99]);
+    case_contrev::<10>(
+        c,
+        1000,
+        &[1, 6, 200, 334, 400, 498, 503, 668, 800, 900, 995, 999],
+    );
+}
+
+fn bench_direct_chunked(c: &mut Criterion) {
+    let len = 10_000;
+
+    let mut group = c.benchmark_group(format!("DirectChunked/{len}/u32"));
+    let mut v: Vec<u32> = (1..=len as u32).collect();
+
+    // gcd(l, len - l) = 5000, 2500, 2000, 1000, 400, 16, 1
+    for l in [5000, 2500, 4000, 1000, 6400, 9984, 3333] {
+        let mid = unsafe { v.as_mut_ptr().add(l) };
+        let r = len - l;
+
+        group.bench_with_input(BenchmarkId::new("Direct", l), &l, |b, _| {
+            b.iter(|| test(ptr_direct_rotate::<u32>, l, mid, r))
+        });
+
+        group.bench_with_input(BenchmarkId::new("Direct (chunked)", l), &l, |b, _| {
+            b.iter(|| test(ptr_direct_rotate_chunked::<u32>, l, mid, r))
+        });
+    }
+
+    group.finish();
+}
+
+/// cargo bench --bench=rotations "RotateCopy"
+fn bench_rotate_copy(c: &mut Criterion) {
+    let len = 10_000;
+
+    let mut group = c.benchmark_group(format!("RotateCopy/{len}/u64"));
+    let mut v: Vec<u64> = (1..=len as u64).collect();
+
+    // gcd(l, len - l) = 5000, 2500, 1000, 400, 16, 1, 1
+    for l in [5000, 2500, 1000, 6400, 9984, 3333, 1] {
+        let mid = unsafe { v.as_mut_ptr().add(l) };
+        let r = len - l;
+
+        group.bench_with_input(BenchmarkId::new("Rotate (Copy)", l), &l, |b, _| {
+            b.iter(|| test(ptr_rotate_copy::<u64>, l, mid, r))
+        });
+
+        group.bench_with_input(BenchmarkId::new("Direct", l), &l, |b, _| {
+            b.iter(|| test(ptr_direct_rotate::<u64>, l, mid, r))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_helix_aux(c: &mut Criterion) {
+    let len = 100_000;
+
+    let mut group = c.benchmark_group(format!("HelixAux/{len}/1"));
+    let mut v = seq::<1>(len);
+    let mut buffer = seq::<1>(2);
+
+    for l in [1, 2, 3, 7, 33_334, 99_999] {
+        let mid = unsafe { v.as_mut_ptr().add(l) };
+        let r = len - l;
+
+        group.bench_with_input(BenchmarkId::new("Helix", l), &l, |b, _| {
+            b.iter(|| test(ptr_helix_rotate::<[usize; 1]>, l, mid, r))
+        });
+
+        group.bench_with_input(BenchmarkId::new("Helix (aux)", l), &l, |b, _| {
+            b.iter(|| {
+                buf_test(
+                    ptr_helix_rotate_aux::<[usize; 1]>,
+                    l,
+                    mid,
+                    r,
+                    buffer.as_mut_slice(),
+                )
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_stable_small(c: &mut Criterion) {
+    for len in [5, 10, 16, 23] {
+        let mut group = c.benchmark_group(format!("StableSmall/{len}/1"));
+        let mut v = seq::<1>(len);
+
+        let mut ls = vec![1, len / 3, len / 2, len - 1];
+        ls.dedup();
+
+        for l in ls {
+            let mid = unsafe { v.as_mut_ptr().add(l) };
+            let r = len - l;
+
+            group.bench_with_input(BenchmarkId::new("Stable", l), &l, |b, _| {
+                b.iter(|| test(stable_ptr_rotate::<[usize; 1]>, l, mid, r))
+            });
+        }
+
+        group.finish();
+    }
+}
+
+/// cargo bench --bench=rotations "AutoBuffer"
+fn bench_auto_buffer(c: &mut Criterion) {
+    let len = 1000;
+
+    let mut group = c.benchmark_group(format!("AutoBuffer/{len}/1"));
+    let mut v = seq::<1>(len);
+    let mut buffer = seq::<1>(len / 2);
+
+    for l in [3, 10, 100, 333, 499] {
+        let mid = unsafe { v.as_mut_ptr().add(l) };
+        let r = len - l;
+
+        group.bench_with_input(BenchmarkId::new("Auto buffer", l), &l, |b, _| {
+            b.iter(|| test(ptr_rotate_auto_buffer::<[usize; 1]>, l, mid, r))
+        });
+
+        group.bench_with_input(BenchmarkId::new("Aux", l), &l, |b, _| {
+            b.iter(|| {
+                buf_test(
+                    ptr_aux_rotate::<[usize; 1]>,
+                    l,
+                    mid,
+                    r,
+                    buffer.as_mut_slice(),
+                )
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("Stable", l), &l, |b, _| {
+            b.iter(|| test(stable_ptr_rotate::<[usize; 1]>, l, mid, r))
+        });
+    }
+
+    group.finish();
+}
+
+/// cargo bench --bench=rotations "Midpoint"
+/// cargo bench --features simd --bench=rotations "Midpoint"
+fn bench_midpoint(c: &mut Criterion) {
+    for len in [1_000, 100_000] {
+        let mut group = c.benchmark_group(format!("Midpoint/{len}/u32"));
+        let mut v: Vec<u32> = (0..len as u32).collect();
+
+        // `diff == 0`: the equal-halves branch, i.e. `swap_halves`
+        let l = len / 2;
+        let mid = unsafe { v.as_mut_ptr().add(l) };
+
+        group.bench_with_input(BenchmarkId::new("Contrev", l), &l, |b, _| {
+            b.iter(|| test(ptr_contrev_rotate::<u32>, l, mid, l))
+        });
+
+        group.bench_with_input(BenchmarkId::new("GM", l), &l, |b, _| {
+            b.iter(|| test(ptr_griesmills_rotate::<u32>, l, mid, l))
+        });
+
+        group.bench_with_input(BenchmarkId::new("Rotate", l), &l, |b, _| {
+            b.iter(|| test(ptr_rotate::<u32>, l, mid, l))
+        });
+
+        group.finish();
+    }
+}
+
+/// cargo bench --bench=rotations "RevEdge"
+fn bench_rev_edge(c: &mut Criterion) {
+    let len = 100_001;
+
+    let mut group = c.benchmark_group(format!("RevEdge/{len}/1"));
+    let mut v = seq::<1>(len);
+
+    for l in [1, 2, len - 2, len - 1] {
+        let mid = unsafe { v.as_mut_ptr().add(l) };
+        let r = len - l;
+
+        group.bench_with_input(BenchmarkId::new("Edge", l), &l, |b, _| {
+            b.iter(|| test(ptr_edge_rotate::<[usize; 1]>, l, mid, r))
+        });
+
+        // never delegates to `ptr_edge_rotate`
+        group.bench_with_input(BenchmarkId::new("Rev (full)", l), &l, |b, _| {
+            b.iter(|| unsafe { ptr_reversal_rotate_progress::<[usize; 1]>(l, mid, r, &mut |_| {}) })
+        });
+    }
+
+    group.finish();
+}
+
+/// cargo bench --bench=rotations "SmallShift"
+fn bench_small_shift(c: &mut Criterion) {
+    let len = 100_000;
+
+    let mut group = c.benchmark_group(format!("SmallShift/{len}/1"));
+    let mut v = seq::<1>(len);
+
+    for l in [1, 2, 3, 4, len - 3] {
+        let mid = unsafe { v.as_mut_ptr().add(l) };
+        let r = len - l;
+
+        group.bench_with_input(BenchmarkId::new("Small shift", l), &l, |b, _| {
+            b.iter(|| test(ptr_small_shift_rotate::<[usize; 1]>, l, mid, r))
+        });
+
+        group.bench_with_input(BenchmarkId::new("Edge", l), &l, |b, _| {
+            b.iter(|| test(ptr_edge_rotate::<[usize; 1]>, l, mid, r))
+        });
+
+        group.bench_with_input(BenchmarkId::new("Stable", l), &l, |b, _| {
+            b.iter(|| test(stable_ptr_rotate::<[usize; 1]>, l, mid, r))
+        });
+
+        group.bench_with_input(BenchmarkId::new("Rev", l), &l, |b, _| {
+            b.iter(|| test(ptr_reversal_rotate::<[usize; 1]>, l, mid, r))
+        });
+    }
+
+    group.finish();
+}
+
+/// cargo bench --bench=rotations "LargeT"
+fn bench_large_t(c: &mut Criterion) {
+    let len = 10_000;
+
+    let mut group = c.benchmark_group(format!("LargeT/{len}/10"));
+    let mut v = seq::<10>(len);
+    let mut buffer = seq::<10>(32);
+
+    for l in [1, 20, 1000, 3334, 4990, 5000, 6666, 9999] {
+        let mid = unsafe { v.as_mut_ptr().add(l) };
+        let r = len - l;
+
+        group.bench_with_input(BenchmarkId::new("Large T", l), &l, |b, _| {
+            b.iter(|| {
+                buf_test(
+                    ptr_rotate_large_t::<[usize; 10]>,
+                    l,
+                    mid,
+                    r,
+                    buffer.as_mut_slice(),
+                )
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("Stable", l), &l, |b, _| {
+            b.iter(|| test(stable_ptr_rotate::<[usize; 10]>, l, mid, r))
+        });
+    }
+
+    group.finish();
+}
+
+/// cargo bench --bench=rotations "EdgeShift"
+fn bench_edge_shift(c: &mut Criterion) {
+    fn case<const N: usize>(c: &mut Criterion, len: usize) {
+        let mut group = c.benchmark_group(format!("EdgeShift/{len}/{N}"));
+        let mut v = seq::<N>(len);
+
+        for l in [1, len - 1] {
+            let mid = unsafe { v.as_mut_ptr().add(l) };
+            let r = len - l;
+
+            group.bench_with_input(BenchmarkId::new("Edge", l), &l, |b, _| {
+                b.iter(|| test(ptr_edge_rotate::<[usize; N]>, l, mid, r))
+            });
+
+            // the shift alone, as `ptr_edge_rotate` did it before and with a `memmove`
+            let (src, count, offset) = if l == 1 {
+                (mid, r, -1)
+            } else {
+                (unsafe { mid.sub(l) }, l, 1)
+            };
+
+            group.bench_with_input(BenchmarkId::new("shift_by", l), &l, |b, _| {
+                b.iter(|| unsafe { utils::shift_by(src, count, offset) })
+            });
+
+            group.bench_with_input(BenchmarkId::new("ptr::copy", l), &l, |b, _| {
+                b.iter(|| unsafe { std::ptr::copy(src, src.offset(offset), count) })
+            });
+        }
+
+        group.finish();
+    }
+
+    case::<1>(c, 100_000);
+    case::<4>(c, 100_000);
+    case::<20>(c, 100_000);
+    case::<1>(c, 1_000);
+    case::<4>(c, 1_000);
+    case::<20>(c, 1_000);
+    case::<4>(c, 100);
+}
+
+/// cargo bench --bench=rotations "TiledRev"
+fn bench_tiled_rev(c: &mut Criterion) {
+    let len = 10_000_000;
+
+    let mut group = c.benchmark_group(format!("TiledRev/{len}/1"));
+    group.sample_size(10);
+
+    let mut v = seq::<1>(len);
+
+    for l in [1_000, 3_333_333, 4_999_999] {
+        let mid = unsafe { v.as_mut_ptr().add(l) };
+        let r = len - l;
+
+        for tile in [4096, reversal_tile::<[usize; 1]>()] {
+            group.bench_with_input(
+                BenchmarkId::new(format!("Rev (tiled {tile})"), l),
+                &l,
+                |b, _| {
+                    b.iter(|| unsafe { ptr_tiled_reversal_rotate::<[usize; 1]>(l, mid, r, tile) })
+                },
+            );
+        }
+
+        group.bench_with_input(BenchmarkId::new("Rev", l), &l, |b, _| {
+            b.iter(|| test(ptr_reversal_rotate::<[usize; 1]>, l, mid, r))
+        });
+    }
+
+    group.finish();
+}
+
+/// cargo bench --bench=rotations "GcdCycles"
+fn bench_gcd_cycles(c: &mut Criterion) {
+    // 2^4 * 3^2 * 5 * 7 * 11 * 13
+    let len = 720_720;
+
+    let mut group = c.benchmark_group(format!("GcdCycles/{len}/1"));
+    group.sample_size(20);
+
+    let mut v = seq::<1>(len);
+
+    // rotation_cycles = 1, 2, 12, 360
+    for l in [240_241, 240_242, 240_252, 240_840] {
+        let mid = unsafe { v.as_mut_ptr().add(l) };
+        let r = len - l;
+
+        let id = format!("{l} (cycles {})", rotation_cycles(l, r));
+
+        group.bench_with_input(BenchmarkId::new("Direct", &id), &l, |b, _| {
+            b.iter(|| test(ptr_direct_rotate::<[usize; 1]>, l, mid, r))
+        });
+
+        group.bench_with_input(BenchmarkId::new("Contrev", &id), &l, |b, _| {
+            b.iter(|| test(ptr_contrev_rotate::<[usize; 1]>, l, mid, r))
+        });
+
+        group.bench_with_input(BenchmarkId::new("Rev", &id), &l, |b, _| {
+            b.iter(|| test(ptr_reversal_rotate::<[usize; 1]>, l, mid, r))
+        });
+    }
+
+    group.finish();
+}
+
+/// cargo bench --bench=rotations "VsStd"
+fn bench_vs_std(c: &mut Criterion) {
+    fn case<const N: usize>(c: &mut Criterion, len: usize) {
+        let mut group = c.benchmark_group(format!("VsStd/{len}/{N}"));
+
+        let mut v = seq::<N>(len);
+        let mut buffer = seq::<N>(len / 2);
+
+        for l in [1, len / 100, len / 3, len / 2 - 1, len - len / 10] {
+            let mid = unsafe { v.as_mut_ptr().add(l) };
+            let r = len - l;
+
+            group.bench_with_input(BenchmarkId::new("Std", l), &l, |b, _| {
+                let s = unsafe { std::slice::from_raw_parts_mut(mid.sub(l), len) };
+
+                b.iter(|| std_rotate_left(s, l))
+            });
+
+            group.bench_with_input(BenchmarkId::new("Direct", l), &l, |b, _| {
+                b.iter(|| test(ptr_direct_rotate::<[usize; N]>, l, mid, r))
+            });
+
+            group.bench_with_input(BenchmarkId::new("Contrev", l), &l, |b, _| {
+                b.iter(|| test(ptr_contrev_rotate::<[usize; N]>, l, mid, r))
+            });
+
+            group.bench_with_input(BenchmarkId::new("Rev", l), &l, |b, _| {
+                b.iter(|| test(ptr_reversal_rotate::<[usize; N]>, l, mid, r))
+            });
+
+            group.bench_with_input(BenchmarkId::new("Aux", l), &l, |b, _| {
+                b.iter(|| {
+                    buf_test(
+                        ptr_aux_rotate::<[usize; N]>,
+                        l,
+                        mid,
+                        r,
+                        buffer.as_mut_slice(),
+                    )
+                })
+            });
+        }
+
+        group.finish();
+    }
+
+    case::<1>(c, 1_000);
+    case::<1>(c, 100_000);
+    case::<4>(c, 100_000);
+}
+
+fn bench_bytes(c: &mut Criterion) {
+    for len in [64, 1_000, 100_000] {
+        let mut group = c.benchmark_group(format!("Bytes/{len}"));
+        let mut v: Vec<u8> = (0..len).map(|i| i as u8).collect();
+
+        for l in [1, 100, len / 3, len / 2, 5000]
+            .into_iter()
+            .filter(|&l| l < len)
+        {
+            let r = len - l;
+
+            group.bench_with_input(BenchmarkId::new("Bytes", l), &l, |b, _| {
+                b.iter(|| rotate_bytes_left(&mut v, l))
+            });
+
+            group.bench_with_input(BenchmarkId::new("Stable", l), &l, |b, _| {
+                let mid = unsafe { v.as_mut_ptr().add(l) };
+
+                b.iter(|| test(stable_ptr_rotate::<u8>, l, mid, r))
+            });
+        }
+
+        group.finish();
+    }
+}
+
+fn bench_contrev_center(c: &mut Criterion) {
+    use Rotation::*;
+
+    let ls = [1, 100, 333, 499, 501, 667, 900, 999];
+
+    case::<10>("ContrevCenter", c, 1000, &ls, vec![Contrev, ContrevV2]);
+    case::<40>("ContrevCenter", c, 1000, &ls, vec![Contrev, ContrevV2]);
+}
+
 fn bench_rev(c: &mut Criterion) {
     // 1 * usize
     case_rev::<1>(c, 15, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14]);
@@ -417,6 +1020,34 @@ fn bench_rev(c: &mut Criterion) {
     );
 }
 
+#[cfg(feature = "parallel")]
+fn bench_parallel(c: &mut Criterion) {
+    let len = 10_000_000;
+
+    let mut group = c.benchmark_group(format!("Parallel/{len}/1"));
+    group.sample_size(10);
+
+    let mut v = seq::<1>(len);
+
+    for l in [1_000, 3_333_333, 5_000_001] {
+        let mid = unsafe { v.as_mut_ptr().add(l) };
+        let r = len - l;
+
+        group.bench_with_input(BenchmarkId::new("Rev", l), &l, |b, _| {
+            b.iter(|| test(ptr_reversal_rotate::<[usize; 1]>, l, mid, r))
+        });
+
+        group.bench_with_input(BenchmarkId::new("Rev (parallel)", l), &l, |b, _| {
+            b.iter(|| test(ptr_reversal_rotate_parallel::<[usize; 1]>, l, mid, r))
+        });
+    }
+
+    group.finish();
+}
+
+#[cfg(not(feature = "parallel"))]
+fn bench_parallel(_c: &mut Criterion) {}
+
 fn bench_gm(c: &mut Criterion) {
     // 1 * usize
     case_gm::<1>(c, 15, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14]);
@@ -426,13 +1057,13 @@ fn bench_gm(c: &mut Criterion) {
     case_gm::<1>(
         c,
         1000,
-        &[2, 32, 200, 334, 400, 485, 516, 668, 800, 900, 969, 998],
+        &[2, 32, 200, 334, 400, 485, 516, 668, 800, 900, 969, 998, 999],
     );
     case_gm::<1>(
         c,
         10000,
         &[
-            2, 32, 2000, 3334, 4000, 4985, 5016, 6668, 8000, 9000, 9969, 9998,
+            2, 32, 2000, 3334, 4000, 4985, 5016, 6668, 8000, 9000, 9969, 9998, 9999,
         ],
     );
 
@@ -480,7 +1111,7 @@ criterion_group! {
 
     config = Criterion::default();
 
-    targets = bench_buf, bench_contrev, bench_rev, bench_gm, bench_short
+    targets = bench_buf, bench_contrev, bench_contrev_buf, bench_direct_chunked, bench_helix_aux, bench_stable_small, bench_bytes, bench_contrev_center, bench_rev, bench_parallel, bench_gm, bench_short, bench_tiny, bench_auto_buffer, bench_rev_edge, bench_small_shift, bench_large_t, bench_rotate_copy, bench_edge_shift, bench_tiled_rev, bench_gcd_cycles, bench_vs_std, bench_midpoint
 }
 
 criterion_main!(benches);
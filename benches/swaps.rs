@@ -1,4 +1,4 @@
-#![feature(slice_swap_unchecked)]
+#![cfg_attr(feature = "nightly", feature(slice_swap_unchecked))]
 
 use criterion::measurement::WallTime;
 use criterion::{criterion_group, criterion_main, BenchmarkGroup, BenchmarkId, Criterion};
@@ -10,11 +10,15 @@ use rust_rotations::utils::*;
 use std::ptr;
 
 fn seq<const N: usize>(size: usize) -> Vec<[usize; N]> {
-    let mut v = vec![[0; N]; size];
-    for i in 0..size {
-        v[i] = [i + 1; N];
-    }
-    v
+    (1..=size).map(|i| [i; N]).collect()
+}
+
+unsafe fn swap_forward_blocks_8<T>(x: *mut T, y: *mut T, count: usize) {
+    swap_forward_blocks(x, y, count, 8)
+}
+
+unsafe fn swap_backward_blocks_8<T>(x: *mut T, y: *mut T, count: usize) {
+    swap_backward_blocks(x, y, count, 8)
 }
 
 /// ```text
@@ -72,9 +76,9 @@ fn case_swap_forward<const N: usize>(c: &mut Criterion, len: usize) {
     let mut v = seq::<N>(len * 10 + 1);
 
     for mut d in 1..10 {
-        d = d * len;
+        d *= len;
 
-        let start = *&v[..].as_mut_ptr();
+        let start = v.as_mut_ptr();
 
         group.bench_with_input(BenchmarkId::new("utils::swap_forward", d), &d, |b, _| {
             b.iter(|| forward_test(swap_forward::<[usize; N]>, start, d, len))
@@ -84,6 +88,18 @@ fn case_swap_forward<const N: usize>(c: &mut Criterion, len: usize) {
             b.iter(|| forward_test(swap_backward::<[usize; N]>, start, d, len))
         });
 
+        group.bench_with_input(
+            BenchmarkId::new("utils::swap_forward_blocks(8)", d),
+            &d,
+            |b, _| b.iter(|| forward_test(swap_forward_blocks_8::<[usize; N]>, start, d, len)),
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("utils::swap_backward_blocks(8)", d),
+            &d,
+            |b, _| b.iter(|| forward_test(swap_backward_blocks_8::<[usize; N]>, start, d, len)),
+        );
+
         group.bench_with_input(
             BenchmarkId::new("ptr::swap_nonoverlapping", d),
             &d,
@@ -108,7 +124,7 @@ fn case_swap_backward<const N: usize>(c: &mut Criterion, len: usize) {
     let mut v = seq::<N>(10 * len + 1);
 
     for mut d in 1..10 {
-        d = d * len;
+        d *= len;
 
         let end = *unsafe { &v[..].as_mut_ptr().add(10 * len + 1) };
 
@@ -120,6 +136,18 @@ fn case_swap_backward<const N: usize>(c: &mut Criterion, len: usize) {
             b.iter(|| backward_test(swap_backward::<[usize; N]>, end, d, len))
         });
 
+        group.bench_with_input(
+            BenchmarkId::new("utils::swap_forward_blocks(8)", d),
+            &d,
+            |b, _| b.iter(|| backward_test(swap_forward_blocks_8::<[usize; N]>, end, d, len)),
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("utils::swap_backward_blocks(8)", d),
+            &d,
+            |b, _| b.iter(|| backward_test(swap_backward_blocks_8::<[usize; N]>, end, d, len)),
+        );
+
         group.bench_with_input(
             BenchmarkId::new("ptr::swap_nonoverlapping", d),
             &d,
@@ -130,32 +158,6 @@ fn case_swap_backward<const N: usize>(c: &mut Criterion, len: usize) {
     group.finish();
 }
 
-/// # Swap
-///
-/// Swap elements `p.add(x)` and `p.add(y)``.
-///
-/// ## Safety
-///
-/// The specified elements must be valid for reading and writing.
-///
-/// ## Example
-///
-/// ```text
-///            x                 y
-/// [ 1  2  3  4  5  6  7  8  9 10 11 12 13 14 15]  // swap
-///            └─────────────────┘
-/// [ 1  .  3 10  5  .  .  .  9 11  .  .  . 15]
-/// ```
-#[inline(always)]
-unsafe fn swap_ref<T>(p: *mut T, x: usize, y: usize) {
-    let (x, y) = (p.add(x), p.add(y));
-
-    let x_ref = unsafe { &mut *x.cast::<T>() };
-    let y_ref = unsafe { &mut *y.cast::<T>() };
-
-    std::mem::swap(x_ref, y_ref);
-}
-
 fn case_swap<const N: usize>(group: &mut BenchmarkGroup<WallTime>) {
     let mut v = seq::<1>(3);
 
@@ -200,6 +202,7 @@ fn case_swap<const N: usize>(group: &mut BenchmarkGroup<WallTime>) {
         })
     });
 
+    #[cfg(feature = "nightly")]
     group.bench_with_input(BenchmarkId::new("slice::swap_unchecked", N), &1, |b, _| {
         b.iter(|| unsafe {
             let slice = std::slice::from_raw_parts_mut(start, 3);
@@ -213,7 +216,7 @@ fn case_swap<const N: usize>(group: &mut BenchmarkGroup<WallTime>) {
 }
 
 fn bench_swap(c: &mut Criterion) {
-    let mut group = c.benchmark_group(format!("Swap pair"));
+    let mut group = c.benchmark_group("Swap pair");
 
     seq_macro::seq!(i in 1..=10 {
        case_swap::<i>(&mut group);
@@ -244,6 +247,62 @@ fn bench_swap_backward(c: &mut Criterion) {
     case_swap_backward::<10>(c, 100_000);
 }
 
+fn case_reverse<T: Copy>(c: &mut Criterion, name: &str, f: impl Fn(usize) -> T) {
+    let mut group = c.benchmark_group(format!("Reverse/{name}"));
+
+    for len in [15, 100, 1000, 100_000] {
+        let mut v: Vec<T> = (0..len).map(&f).collect();
+
+        group.bench_with_input(BenchmarkId::new("reverse_slice", len), &len, |b, _| {
+            b.iter(|| unsafe { reverse_slice(v.as_mut_ptr(), len) })
+        });
+
+        group.bench_with_input(BenchmarkId::new("slice.reverse", len), &len, |b, _| {
+            b.iter(|| v.reverse())
+        });
+    }
+
+    group.finish();
+}
+
+/// cargo bench --features simd --bench=swaps "Reverse"
+fn bench_reverse(c: &mut Criterion) {
+    case_reverse(c, "u8", |i| i as u8);
+    case_reverse(c, "u16", |i| i as u16);
+    case_reverse(c, "u32", |i| i as u32);
+    case_reverse(c, "usize", |i| i);
+}
+
+fn case_halves<T: Copy>(c: &mut Criterion, name: &str, f: impl Fn(usize) -> T) {
+    let mut group = c.benchmark_group(format!("Halves/{name}"));
+
+    for len in [16, 100, 1000, 100_000] {
+        let mut v: Vec<T> = (0..2 * len).map(&f).collect();
+
+        group.bench_with_input(BenchmarkId::new("swap_halves", len), &len, |b, _| {
+            b.iter(|| unsafe { swap_halves(v.as_mut_ptr(), v.as_mut_ptr().add(len), len) })
+        });
+
+        group.bench_with_input(
+            BenchmarkId::new("swap_nonoverlapping", len),
+            &len,
+            |b, _| {
+                b.iter(|| unsafe {
+                    std::ptr::swap_nonoverlapping(v.as_mut_ptr(), v.as_mut_ptr().add(len), len)
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+/// cargo bench --features simd --bench=swaps "Halves"
+fn bench_halves(c: &mut Criterion) {
+    case_halves(c, "u8", |i| i as u8);
+    case_halves(c, "u32", |i| i as u32);
+}
+
 criterion_group! {
     name = benches;
 
@@ -251,7 +310,7 @@ criterion_group! {
     config = Criterion::default();
              // .sample_size(500)
 
-    targets = bench_swap_backward, bench_swap_forward, bench_swap
+    targets = bench_swap_backward, bench_swap_forward, bench_swap, bench_reverse, bench_halves
 }
 
 criterion_main!(benches);
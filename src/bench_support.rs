@@ -0,0 +1,106 @@
+/*
+Copyright (C) 2023 Valentin Vasilev (3volny@gmail.com).
+*/
+
+//! Helpers to benchmark the rotations with `criterion` from other crates, without copying the
+//! glue of `benches/rotations.rs`. Available with the `bench` feature.
+//!
+//! Test support, not a stable API.
+
+use crate::{rotate_with, rotate_with_buffer, BufRotationKind, RotationKind};
+use criterion::{BenchmarkId, Criterion};
+
+/// Returns `[[1; N], [2; N], ..., [len; N]]`: elements of `N` `usize`s to rotate.
+pub fn seq<const N: usize>(len: usize) -> Vec<[usize; N]> {
+    (1..=len).map(|i| [i; N]).collect()
+}
+
+/// # Bench kind
+///
+/// Benchmarks every `kind` on a slice of `len` elements of `N` `usize`s, rotating it left by
+/// every `l` in `lefts` with [`rotate_with`].
+///
+/// The group is named `Kind/{len}/{N}`, the benchmarks `{kind:?}/{l}`.
+///
+/// ## Panics
+///
+/// This function will panic if some `l` in `lefts` is greater than `len`.
+///
+/// ## Example
+///
+/// ```no_run
+/// use criterion::Criterion;
+/// use rust_rotations::{bench_support::bench_kind, RotationKind};
+///
+/// let mut c = Criterion::default();
+///
+/// bench_kind::<1>(&mut c, &RotationKind::ALL, 1000, &[1, 333, 500, 999]);
+/// ```
+pub fn bench_kind<const N: usize>(
+    c: &mut Criterion,
+    kinds: &[RotationKind],
+    len: usize,
+    lefts: &[usize],
+) {
+    let mut group = c.benchmark_group(format!("Kind/{len}/{N}"));
+    let mut v = seq::<N>(len);
+
+    for &l in lefts {
+        for &kind in kinds {
+            group.bench_with_input(BenchmarkId::new(format!("{kind:?}"), l), &l, |b, &l| {
+                b.iter(|| rotate_with(kind, &mut v, l))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+/// # Bench buffered kind
+///
+/// Same as [`bench_kind`] for the buffered rotations, dispatched with [`rotate_with_buffer`].
+/// The buffer holds `len` elements, enough for any `kind`.
+///
+/// The group is named `BufKind/{len}/{N}`.
+///
+/// ## Panics
+///
+/// This function will panic if some `l` in `lefts` is greater than `len`.
+pub fn bench_buf_kind<const N: usize>(
+    c: &mut Criterion,
+    kinds: &[BufRotationKind],
+    len: usize,
+    lefts: &[usize],
+) {
+    let mut group = c.benchmark_group(format!("BufKind/{len}/{N}"));
+    let mut v = seq::<N>(len);
+    let mut buffer = seq::<N>(len);
+
+    for &l in lefts {
+        for &kind in kinds {
+            group.bench_with_input(BenchmarkId::new(format!("{kind:?}"), l), &l, |b, &l| {
+                b.iter(|| rotate_with_buffer(kind, &mut v, l, &mut buffer))
+            });
+        }
+    }
+
+    group.finish();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench_support::*;
+    use std::time::Duration;
+
+    #[test]
+    fn bench_kind_runs() {
+        let mut c = Criterion::default()
+            .sample_size(10)
+            .warm_up_time(Duration::from_millis(1))
+            .measurement_time(Duration::from_millis(1))
+            .without_plots();
+
+        bench_kind::<1>(&mut c, &[RotationKind::Direct], 10, &[3]);
+        bench_buf_kind::<2>(&mut c, &[BufRotationKind::Aux], 10, &[3]);
+    }
+}
@@ -23,11 +23,21 @@ TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE
 SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 */
 
-use crate::copy;
+use crate::copy_auto;
+use crate::helix_reduce;
+use crate::is_zst;
 use crate::ptr_contrev_rotate;
 use crate::ptr_edge_rotate;
-use std::cmp;
-use std::ptr;
+use crate::reverse_slice;
+use crate::swap_halves;
+use core::cmp;
+use core::mem::{self, MaybeUninit};
+use core::ptr;
+
+#[cfg(feature = "alloc")]
+use crate::slice::split_at_ptr;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 /// # Auxiliary rotation
 ///
@@ -47,7 +57,13 @@ use std::ptr;
 ///
 /// ## Safety
 ///
-/// The specified range must be valid for reading and writing.
+/// 1. The specified range must be valid for reading and writing;
+/// 2. The `buffer` length must be at least `min(left, right)`;
+/// 3. The `buffer` must not overlap the specified range.
+///
+/// Conditions 2 and 3 are checked in debug builds. The `buffer` may be adjacent to the range,
+/// e.g. the spare tail of the same `Vec`: adjacent regions don't overlap, so the
+/// `ptr::copy_nonoverlapping` transfers are sound.
 ///
 /// ## Example
 ///
@@ -87,25 +103,260 @@ use std::ptr;
 /// [ 1  .  .  4* 5  .  .  .  .  . 11:12 ~~~~~ 15]
 /// ```
 pub unsafe fn ptr_aux_rotate<T>(left: usize, mid: *mut T, right: usize, buffer: &mut [T]) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    debug_check_buffer(
+        left,
+        mid,
+        right,
+        buffer,
+        cmp::min(left, right),
+        "ptr_aux_rotate",
+    );
+
+    ptr_aux_rotate_to(left, mid, right, buffer.as_mut_ptr());
+}
+
+/// # Buffer too small
+///
+/// Error returned by [`ptr_aux_rotate_checked`] when the `buffer` can't hold the smallest side.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BufferTooSmall {
+    /// Required buffer length, `min(left, right)`.
+    pub required: usize,
+    /// Actual buffer length.
+    pub len: usize,
+}
+
+impl core::fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "buffer length = {} < {}", self.len, self.required)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BufferTooSmall {}
+
+/// # Auxiliary rotation (Checked)
+///
+/// Same as [`ptr_aux_rotate`], but returns `Err(BufferTooSmall { required, len })` and leaves
+/// the range untouched if `buffer.len() < min(left, right)`, instead of relying on the caller.
+/// Never falls back to an in-place rotation.
+///
+/// ## Safety
+///
+/// 1. The specified range must be valid for reading and writing;
+/// 2. The `buffer` must not overlap the range.
+///
+/// Condition 2 is checked in debug builds.
+pub unsafe fn ptr_aux_rotate_checked<T>(
+    left: usize,
+    mid: *mut T,
+    right: usize,
+    buffer: &mut [T],
+) -> Result<(), BufferTooSmall> {
+    let required = cmp::min(left, right);
+
+    if buffer.len() < required {
+        return Err(BufferTooSmall {
+            required,
+            len: buffer.len(),
+        });
+    }
+
+    ptr_aux_rotate(left, mid, right, buffer);
+
+    Ok(())
+}
+
+/// Checks, in debug builds only, that the `buffer` holds at least `required` elements and does
+/// not alias the range `[mid-left, mid+right)`.
+#[inline(always)]
+fn debug_check_buffer<T>(
+    left: usize,
+    mid: *const T,
+    right: usize,
+    buffer: &[T],
+    required: usize,
+    name: &str,
+) {
+    debug_assert!(
+        buffer.len() >= required,
+        "{name}: buffer length = {} < {required}",
+        buffer.len()
+    );
+
+    if cfg!(debug_assertions) && !buffer.is_empty() {
+        let start = mid.wrapping_sub(left) as usize;
+        let end = mid.wrapping_add(right) as usize;
+
+        let buf_start = buffer.as_ptr() as usize;
+        let buf_end = buffer.as_ptr().wrapping_add(buffer.len()) as usize;
+
+        debug_assert!(
+            buf_end <= start || end <= buf_start,
+            "{name}: buffer aliases the rotated range"
+        );
+    }
+}
+
+/// # Auxiliary rotation (Stack)
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// Same as [`ptr_aux_rotate`], but the buffer is a `[usize; 32]` array on the stack, like
+/// *Algorithm 2* of [`stable_ptr_rotate`](crate::stable_ptr_rotate). If
+/// `min(left, right) * size_of::<T>()` does not fit into it, [`ptr_contrev_rotate`] is used instead.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+pub unsafe fn ptr_aux_rotate_stack<T>(left: usize, mid: *mut T, right: usize) {
+    type BufType = [usize; 32];
+
+    if is_zst::<T>() {
+        return;
+    }
+
+    if cmp::min(left, right) > mem::size_of::<BufType>() / mem::size_of::<T>() {
+        ptr_contrev_rotate(left, mid, right);
+        return;
+    }
+
+    // The `[T; 0]` here is to ensure this is appropriately aligned for T
+    let mut rawarray = MaybeUninit::<(BufType, [T; 0])>::uninit();
+    let buf = rawarray.as_mut_ptr() as *mut T;
+
+    ptr_aux_rotate_to(left, mid, right, buf);
+}
+
+/// # Auxiliary rotation (Heap)
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// Same as [`ptr_aux_rotate`], but allocates a buffer of exactly `min(left, right)` elements
+/// itself and frees it on return. For one-shot rotations where managing scratch space (see
+/// [`RotateBuffer`]) is not worth it. The edge cases and `left == right` don't allocate.
+///
+/// Available with the `alloc` feature.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+#[cfg(feature = "alloc")]
+pub unsafe fn ptr_rotate_auto_buffer<T>(left: usize, mid: *mut T, right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    if right <= 2 || left <= 2 {
+        ptr_edge_rotate(left, mid, right);
+        return;
+    }
+
+    if left == right {
+        swap_halves(mid.sub(left), mid, left);
+        return;
+    }
+
+    let mut buffer = Vec::<MaybeUninit<T>>::with_capacity(cmp::min(left, right));
+
+    ptr_aux_rotate_to(left, mid, right, buffer.as_mut_ptr() as *mut T);
+}
+
+/// # Small shift rotation
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// The "rotate by one" optimization generalized to shifts by up to `4`: if
+/// `min(left, right) <= 4`, the small side is stashed in a `[T; 4]` on the stack, the large side is
+/// moved with a single `memmove` and the stash is written back, so every element is moved once.
+/// Otherwise [`stable_ptr_rotate`](crate::stable_ptr_rotate) is used.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+///
+/// ## Example
+///
+/// ```text
+///       mid
+///       |  left = 2, right = 7
+/// [ 1  2* 3  4  5  6  7  8  9]  // stash: [ 1  2]
+///         └─────────────────┘
+///   ┌─────────────────┐
+/// [ 3 ~~~~~~~~~~~~~~~ 9  8  9]  // memmove
+///
+/// [ 3  .  .  .  .  .  9: 1  2]  // write back
+/// ```
+pub unsafe fn ptr_small_shift_rotate<T>(left: usize, mid: *mut T, right: usize) {
+    const SMALL: usize = 4;
+
+    if is_zst::<T>() {
+        return;
+    }
+
+    if left == 0 || right == 0 {
+        return;
+    }
+
+    if cmp::min(left, right) > SMALL {
+        crate::stable_ptr_rotate(left, mid, right);
+        return;
+    }
+
+    let mut stash = MaybeUninit::<[T; SMALL]>::uninit();
+    let stash = stash.as_mut_ptr() as *mut T;
+
+    let start = mid.sub(left);
+
+    if left <= right {
+        ptr::copy_nonoverlapping(start, stash, left);
+        ptr::copy(mid, start, right);
+        ptr::copy_nonoverlapping(stash, start.add(right), left);
+    } else {
+        ptr::copy_nonoverlapping(mid, stash, right);
+        ptr::copy(start, start.add(right), left);
+        ptr::copy_nonoverlapping(stash, start, right);
+    }
+}
+
+/// Auxiliary rotation using `buf` as the buffer. `buf` must be valid for writing
+/// `min(left, right)` elements.
+#[inline(always)]
+unsafe fn ptr_aux_rotate_to<T>(left: usize, mid: *mut T, right: usize, buf: *mut T) {
     if right <= 2 || left <= 2 {
         ptr_edge_rotate(left, mid, right);
         return;
     }
 
     let start = mid.sub(left);
-    let buf = buffer.as_mut_ptr();
     let dim = start.add(right);
 
     if left < right {
         ptr::copy_nonoverlapping(start, buf, left);
-        copy(mid, start, right); // ! see 'ptr_naive_aux_rotate'
+        copy_auto(mid, start, right); // ! see 'ptr_naive_aux_rotate'
         ptr::copy_nonoverlapping(buf, dim, left);
     } else if right < left {
         ptr::copy_nonoverlapping(mid, buf, right);
-        copy(start, dim, left); // !
+        copy_auto(start, dim, left); // !
         ptr::copy_nonoverlapping(buf, start, right);
     } else {
-        ptr::swap_nonoverlapping(start, mid, left);
+        swap_halves(start, mid, left);
     }
 }
 
@@ -168,6 +419,10 @@ pub unsafe fn ptr_aux_rotate<T>(left: usize, mid: *mut T, right: usize, buffer:
 /// [ 1  .  .  4* 5  .  .  .  .  . 11:12 ~~~~~ 15]
 /// ```
 pub unsafe fn ptr_naive_aux_rotate<T>(left: usize, mid: *mut T, right: usize, buffer: &mut [T]) {
+    if is_zst::<T>() {
+        return;
+    }
+
     if right <= 2 || left <= 2 {
         ptr_edge_rotate(left, mid, right);
         return;
@@ -186,7 +441,7 @@ pub unsafe fn ptr_naive_aux_rotate<T>(left: usize, mid: *mut T, right: usize, bu
         ptr::copy(start, dim, left);
         ptr::copy_nonoverlapping(buf, start, right);
     } else {
-        ptr::swap_nonoverlapping(start, mid, left);
+        swap_halves(start, mid, left);
     }
 }
 
@@ -203,10 +458,14 @@ pub unsafe fn ptr_naive_aux_rotate<T>(left: usize, mid: *mut T, right: usize, bu
 /// requirement from `50%` to `33.(3)%`. Its first known publication
 /// was in *2021* by *Igor van den Hoven*." <<https://github.com/scandum/rotate>>
 ///
+/// Unlike [`ptr_bridge_rotate`], never falls back to *Auxiliary rotation*, so the bridge path can
+/// be benchmarked and used directly when the overlap condition is known to hold.
+///
 /// ## Safety
 ///
 /// 1. The specified range must be valid for reading and writing;
-/// 2. The `buffer` length must be larger than `|right - left|`.
+/// 2. `cmp::min(left, right) > |left - right|`, unless `cmp::min(left, right) <= 2`;
+/// 3. `buf` must be valid for writing `|left - right|` elements and must not overlap the range.
 ///
 /// # Example:
 ///
@@ -279,17 +538,16 @@ pub unsafe fn ptr_naive_aux_rotate<T>(left: usize, mid: *mut T, right: usize, bu
 ///   ┌─────┬──────────────────────────────────────────┴─┘
 /// [ 1 ~~~ 3  4  .  6* 7  .  9:10  .  .  .  . 15]
 /// ```
-unsafe fn ptr_bridge_rotate_simple<T>(left: usize, mid: *mut T, right: usize, buffer: &mut [T]) {
+pub unsafe fn ptr_bridge_rotate_simple<T>(left: usize, mid: *mut T, right: usize, buf: *mut T) {
+    if is_zst::<T>() {
+        return;
+    }
+
     if right <= 2 || left <= 2 {
         ptr_edge_rotate(left, mid, right);
         return;
     }
 
-    // type BufType = [usize; 32];
-    // let mut rawarray = MaybeUninit::<(BufType, [T; 0])>::uninit();
-    // let buf = rawarray.as_mut_ptr() as *mut T;
-
-    let buf = buffer.as_mut_ptr();
     let bridge = left.abs_diff(right);
 
     // if cmp::min(left, right) <= bridge {
@@ -321,7 +579,7 @@ unsafe fn ptr_bridge_rotate_simple<T>(left: usize, mid: *mut T, right: usize, bu
 
         ptr::copy_nonoverlapping(buf, a, bridge);
     } else {
-        ptr::swap_nonoverlapping(mid.sub(left), mid, right);
+        swap_halves(mid.sub(left), mid, right);
     }
 }
 
@@ -351,7 +609,10 @@ unsafe fn ptr_bridge_rotate_simple<T>(left: usize, mid: *mut T, right: usize, bu
 /// ## Safety
 ///
 /// 1. The specified range must be valid for reading and writing;
-/// 2. The `buffer` length must be larger than `min(|right - left|, left, right)`.
+/// 2. The `buffer` length must be larger than `min(|right - left|, left, right)`;
+/// 3. The `buffer` must not overlap the specified range.
+///
+/// Conditions 2 and 3 are checked in debug builds.
 ///
 /// # Example:
 ///
@@ -425,14 +686,274 @@ unsafe fn ptr_bridge_rotate_simple<T>(left: usize, mid: *mut T, right: usize, bu
 /// [ 1 ~~~ 3  4  .  6* 7  .  9:10  .  .  .  . 15]
 /// ```
 pub unsafe fn ptr_bridge_rotate<T>(left: usize, mid: *mut T, right: usize, buffer: &mut [T]) {
+    ptr_bridge_rotate_with_policy(
+        left,
+        mid,
+        right,
+        buffer,
+        BridgePolicy::Auto { min_bridge: 0 },
+    );
+}
+
+/// Chooses between *Bridge* and *Auxiliary* rotations in [`ptr_bridge_rotate_with_policy`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BridgePolicy {
+    /// Always use *Bridge rotation*. The `buffer` must hold `|right - left|` elements.
+    AlwaysBridge,
+    /// Always use *Auxiliary rotation*. The `buffer` must hold `min(left, right)` elements.
+    AlwaysAux,
+    /// Use *Bridge rotation* if `min_bridge <= |right - left| < min(left, right)`, otherwise use
+    /// *Auxiliary rotation*. The `buffer` must hold `|right - left|` elements in the first case and
+    /// `min(left, right)` elements in the second one.
+    ///
+    /// `Auto { min_bridge: 0 }` is the [`ptr_bridge_rotate`] behaviour.
+    Auto { min_bridge: usize },
+}
+
+/// # Bridge rotation (with policy)
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// Same as [`ptr_bridge_rotate`], but the choice between *Bridge* and *Auxiliary* rotations is made
+/// by the `policy`, see [`BridgePolicy`].
+///
+/// ## Safety
+///
+/// 1. The specified range must be valid for reading and writing;
+/// 2. The `buffer` length must be at least the number of elements required by the `policy`:
+///    * `AlwaysBridge` -- `|right - left|`;
+///    * `AlwaysAux` -- `min(left, right)`;
+///    * `Auto { .. }` -- `min(|right - left|, left, right)` if `min_bridge == 0`, `min(left, right)`
+///      otherwise;
+/// 3. The `buffer` must not overlap the specified range.
+///
+/// Conditions 2 and 3 are checked in debug builds.
+pub unsafe fn ptr_bridge_rotate_with_policy<T>(
+    left: usize,
+    mid: *mut T,
+    right: usize,
+    buffer: &mut [T],
+    policy: BridgePolicy,
+) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    if left == right {
+        swap_halves(mid.sub(left), mid, left);
+        return;
+    }
+
     let bridge = left.abs_diff(right);
 
-    if cmp::min(left, right) <= bridge {
-        ptr_aux_rotate(left, mid, right, buffer);
+    let use_bridge = match policy {
+        BridgePolicy::AlwaysBridge => true,
+        BridgePolicy::AlwaysAux => false,
+        BridgePolicy::Auto { min_bridge } => min_bridge <= bridge && bridge < cmp::min(left, right),
+    };
+
+    let required = if use_bridge {
+        bridge
+    } else {
+        cmp::min(left, right)
+    };
+    debug_check_buffer(left, mid, right, buffer, required, "ptr_bridge_rotate");
+
+    if use_bridge {
+        ptr_bridge_rotate_simple(left, mid, right, buffer.as_mut_ptr());
+    } else {
+        ptr_aux_rotate_to(left, mid, right, buffer.as_mut_ptr());
+    }
+}
+
+/// # Contrev (Conjoined triple reversal) rotation (Buffered)
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// Same permutations as in [`ptr_contrev_rotate`], but made block-at-a-time: one block is copied to
+/// the `buffer`, the other three are moved with `ptr::copy_nonoverlapping` (and reversed in place
+/// where the pointers go in opposite directions), and the buffer is put back into the vacant block.
+/// Blocks are at most `min(buffer.len(), left, right)` long, so they never overlap.
+///
+/// If `buffer.len() <= 1` this is exactly [`ptr_contrev_rotate`].
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+///
+/// ## Example
+///
+/// ```text
+///                            mid
+///   ls-->               <--le|rs-->       <--re
+/// [ 1  2  3  4  5  6: 7  8  9* a  b  c  d  e  f]  // (ls, le, re, rs), block = 2
+/// [ a  b  3  .  .  .  7  2  1  f  e  c  d  8  9]  // (ls, le, re, rs), block = 1
+/// [ a  .  c  4  5  6  3  2  1  f  e  d  7  .  9]  // (ls, le, re),     block = 1
+/// [ a  .  .  d  5  4  3  2  1  f  e  6  .  .  9]  // reverse
+/// [ a ~~~~~~~~~ e  f: 1  2  3* 4 ~~~~~~~~~~~~ 9]
+/// ```
+pub unsafe fn ptr_contrev_rotate_buffered<T>(
+    left: usize,
+    mid: *mut T,
+    right: usize,
+    buffer: &mut [T],
+) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    let block = cmp::min(buffer.len(), cmp::min(left, right));
+
+    if left <= 2 || right <= 2 || left == right || block <= 1 {
+        ptr_contrev_rotate(left, mid, right);
+        return;
+    }
+
+    let buf = buffer.as_mut_ptr();
+
+    // `le` and `re` point right after their blocks
+    let (mut ls, mut le) = (mid.sub(left), mid);
+    let (mut rs, mut re) = (mid, mid.add(right));
+
+    let half_min = cmp::min(left, right) / 2;
+    let half_max = cmp::max(left, right) / 2;
+
+    let mut count = half_min;
+
+    while count > 0 {
+        let bs = cmp::min(block, count);
+
+        le = le.sub(bs);
+        re = re.sub(bs);
+
+        // Permutation (ls, le, re, rs)
+        ptr::copy_nonoverlapping(ls, buf, bs);
+        ptr::copy_nonoverlapping(rs, ls, bs);
+        ptr::copy_nonoverlapping(re, rs, bs);
+        reverse_slice(rs, bs);
+        ptr::copy_nonoverlapping(le, re, bs);
+        ptr::copy_nonoverlapping(buf, le, bs);
+        reverse_slice(le, bs);
+
+        ls = ls.add(bs);
+        rs = rs.add(bs);
+        count -= bs;
+    }
+
+    count = half_max - half_min;
+
+    if left > right {
+        while count > 0 {
+            let bs = cmp::min(block, count);
+
+            le = le.sub(bs);
+            re = re.sub(bs);
+
+            // (ls, le, re)
+            ptr::copy_nonoverlapping(ls, buf, bs);
+            ptr::copy_nonoverlapping(re, ls, bs);
+            reverse_slice(ls, bs);
+            ptr::copy_nonoverlapping(le, re, bs);
+            ptr::copy_nonoverlapping(buf, le, bs);
+            reverse_slice(le, bs);
+
+            ls = ls.add(bs);
+            count -= bs;
+        }
+    } else {
+        while count > 0 {
+            let bs = cmp::min(block, count);
+
+            re = re.sub(bs);
+
+            // (rs, re, ls)
+            ptr::copy_nonoverlapping(ls, buf, bs);
+            ptr::copy_nonoverlapping(rs, ls, bs);
+            ptr::copy_nonoverlapping(re, rs, bs);
+            reverse_slice(rs, bs);
+            ptr::copy_nonoverlapping(buf, re, bs);
+            reverse_slice(re, bs);
+
+            ls = ls.add(bs);
+            rs = rs.add(bs);
+            count -= bs;
+        }
+    }
+
+    reverse_slice(ls, re.offset_from(ls) as usize);
+}
+
+/// # Helix rotation (Auxiliary)
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// Same as [`ptr_helix_rotate`](crate::ptr_helix_rotate), but the residual with `1` or `2`
+/// elements on the smallest side is finished through the `buffer`, if it can hold them: the
+/// smallest side is copied out, the other one is moved with a single `core::ptr::copy`
+/// (`memmove`) and the smallest side is copied back. Otherwise, and for equal sides, the residual
+/// is finished with [`ptr_edge_rotate`].
+///
+/// ## Safety
+///
+/// 1. The specified range must be valid for reading and writing;
+/// 2. The `buffer` must not overlap the range.
+///
+/// Condition 2 is checked in debug builds.
+///
+/// ## Example
+///
+/// ```text
+///                         mid
+///          left = 8       |      right = 7
+/// [ 1  2  3  4  5  6  7: 8* a  b  c  d  e  f  g]  // swap <--
+///   └────────────────/\──|                    |
+///                    \/  └────────────────────┘
+/// [ g *a  -  -  -  - :f] 1 ~~~~~~~~~~~~~~~~~~ 8   // buf = [g], memmove <--
+/// [ a  -  -  -  - :f  ✘] 1  .  .  .  .  .  .  8   // copy buf
+/// [ a *b ~~~~~~~~~ f :g] 1  .  .  .  .  .  .  8
+/// ```
+pub unsafe fn ptr_helix_rotate_aux<T>(left: usize, mid: *mut T, right: usize, buffer: &mut [T]) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    debug_check_buffer(left, mid, right, buffer, 0, "ptr_helix_rotate_aux");
+
+    let (left, mid, right) = helix_reduce(left, mid, right);
+
+    if left == 0 || right == 0 {
         return;
     }
 
-    ptr_bridge_rotate_simple(left, mid, right, buffer);
+    if left == right || buffer.len() < cmp::min(left, right) {
+        ptr_edge_rotate(left, mid, right);
+        return;
+    }
+
+    let start = mid.sub(left);
+    let buf = buffer.as_mut_ptr();
+
+    if left < right {
+        ptr::copy_nonoverlapping(start, buf, left);
+        ptr::copy(mid, start, right);
+        ptr::copy_nonoverlapping(buf, start.add(right), left);
+    } else {
+        ptr::copy_nonoverlapping(mid, buf, right);
+        ptr::copy(start, start.add(right), left);
+        ptr::copy_nonoverlapping(buf, start, right);
+    }
 }
 
 /// # Trinity (Conjoined triple reversal + Bridge) rotation
@@ -444,7 +965,7 @@ pub unsafe fn ptr_bridge_rotate<T>(left: usize, mid: *mut T, right: usize, buffe
 /// ## Safety
 ///
 /// 1. The specified range must be valid for reading and writing;
-/// 2. The `buffer` length must be larger than `min(|right - left|, left, right)`.
+/// 2. The `buffer` must not overlap the specified range (checked in debug builds).
 ///
 /// ## Algorithm
 ///
@@ -455,104 +976,234 @@ pub unsafe fn ptr_bridge_rotate<T>(left: usize, mid: *mut T, right: usize, buffe
 /// or bridge rotation on stack memory. Its first known publication was in 2021 by Igor van den Hoven."
 /// <<https://github.com/scandum/rotate>>
 pub unsafe fn ptr_trinity_rotate<T>(left: usize, mid: *mut T, right: usize, buffer: &mut [T]) {
-    if cmp::min(left, right) <= buffer.len() {
-        ptr_aux_rotate(left, mid, right, buffer);
+    if is_zst::<T>() {
+        return;
+    }
+
+    debug_check_buffer(left, mid, right, buffer, 0, "ptr_trinity_rotate");
+
+    ptr_trinity_rotate_to(left, mid, right, buffer.as_mut_ptr(), buffer.len());
+}
+
+/// # Trinity (Conjoined triple reversal + Bridge) rotation (Stack)
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// Same as [`ptr_trinity_rotate`], but the buffer is a `[usize; 32]` array on the stack, as in the
+/// original description:
+///
+/// 1. if `min(left, right)` elements fit into the buffer -- *Auxiliary rotation*;
+/// 2. if the bridge `|right - left|` fits into the buffer -- *Bridge rotation*;
+/// 3. otherwise -- *Contrev rotation*.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+pub unsafe fn ptr_trinity_rotate_inplace<T>(left: usize, mid: *mut T, right: usize) {
+    type BufType = [usize; 32];
+
+    if is_zst::<T>() {
+        return;
+    }
+
+    let capacity = mem::size_of::<BufType>() / mem::size_of::<T>();
+
+    // The `[T; 0]` here is to ensure this is appropriately aligned for T
+    let mut rawarray = MaybeUninit::<(BufType, [T; 0])>::uninit();
+    let buf = rawarray.as_mut_ptr() as *mut T;
+
+    ptr_trinity_rotate_to(left, mid, right, buf, capacity);
+}
+
+/// Trinity rotation using `buf` as the buffer. `buf` must be valid for writing `capacity`
+/// elements.
+#[inline(always)]
+unsafe fn ptr_trinity_rotate_to<T>(
+    left: usize,
+    mid: *mut T,
+    right: usize,
+    buf: *mut T,
+    capacity: usize,
+) {
+    if cmp::min(left, right) <= capacity {
+        ptr_aux_rotate_to(left, mid, right, buf);
         return;
     }
 
     let d = right.abs_diff(left);
 
-    if d <= buffer.len() && d > 3 {
-        ptr_bridge_rotate(left, mid, right, buffer);
+    // `d < min(left, right)` here, so the bridge is never too wide
+    if d <= capacity && d > 3 {
+        ptr_bridge_rotate_simple(left, mid, right, buf);
         return;
     }
 
     ptr_contrev_rotate(left, mid, right);
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::*;
+/// # Large `T` rotation
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// [`stable_ptr_rotate`](crate::stable_ptr_rotate) falls back to *Algorithm 1* (*Direct*) when
+/// `size_of::<T>() > 4 * size_of::<usize>()`, as its stack buffer holds only a few such elements.
+/// With a caller-provided `buffer` that is not needed, so for those `T` this is
+/// [`ptr_trinity_rotate`]: *Auxiliary* if `min(left, right)` fits into the buffer, *Bridge* if the
+/// bridge does, and *Contrev* otherwise. Smaller `T` are rotated with
+/// [`stable_ptr_rotate`](crate::stable_ptr_rotate), and the buffer is unused.
+///
+/// ## Safety
+///
+/// 1. The specified range must be valid for reading and writing;
+/// 2. The `buffer` must not overlap the specified range (checked in debug builds).
+pub unsafe fn ptr_rotate_large_t<T>(left: usize, mid: *mut T, right: usize, buffer: &mut [T]) {
+    if is_zst::<T>() {
+        return;
+    }
 
-    fn div(s: usize, diff: usize) -> (usize, usize) {
-        assert!(s >= diff);
-        assert!(s % 2 == diff % 2);
+    if mem::size_of::<T>() > mem::size_of::<[usize; 4]>() {
+        ptr_trinity_rotate(left, mid, right, buffer);
+    } else {
+        crate::stable_ptr_rotate(left, mid, right);
+    }
+}
 
-        let r = s / 2 - diff / 2;
+/// # Rotate buffer
+///
+/// Reusable buffer for the buffered rotations that grows as needed, so it is never too small.
+///
+/// Every method rotates the slice in-place such that the element at index `k` becomes the first
+/// one, exactly as `<[T]>::rotate_left(k)` does, and panics if `k` is greater than the length of
+/// the slice.
+///
+/// ## Example
+///
+/// ```
+/// use rust_rotations::RotateBuffer;
+///
+/// let mut buffer = RotateBuffer::with_capacity(2);
+/// let mut v = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+///
+/// buffer.rotate_aux(&mut v, 4);
+///
+/// assert_eq!(v, [5, 6, 7, 8, 9, 1, 2, 3, 4]);
+/// assert!(buffer.capacity() >= 4);
+/// ```
+#[cfg(feature = "alloc")]
+pub struct RotateBuffer<T> {
+    buf: Vec<MaybeUninit<T>>,
+}
 
-        (s - r, r)
+#[cfg(feature = "alloc")]
+impl<T> Default for RotateBuffer<T> {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    fn seq(size: usize) -> Vec<usize> {
-        let mut v = vec![0; size];
-        for i in 0..size {
-            v[i] = i + 1;
-        }
-        v
+#[cfg(feature = "alloc")]
+impl<T> RotateBuffer<T> {
+    /// Creates an empty buffer.
+    pub fn new() -> Self {
+        Self { buf: Vec::new() }
     }
 
-    fn prepare(size: usize, diff: usize) -> (Vec<usize>, (usize, *mut usize, usize)) {
-        let (l, r) = div(size, diff);
-        let mut v = seq(size);
+    /// Creates a buffer that holds `n` elements.
+    pub fn with_capacity(n: usize) -> Self {
+        let mut buffer = Self::new();
+        buffer.reserve(n);
 
-        unsafe {
-            let p = &v[..].as_mut_ptr().add(l);
-            (v, (l, p.clone(), r))
-        }
+        buffer
     }
 
-    fn case(
-        buf_rotate: unsafe fn(left: usize, mid: *mut usize, right: usize, buffer: &mut [usize]),
-        size: usize,
-        diff: usize,
-        buffer: &mut [usize],
-    ) {
-        let (vec, (l, p, r)) = prepare(size, diff);
+    /// Number of elements the buffer holds without growing.
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
 
-        let mut s = seq(size);
+    /// Grows the buffer to hold at least `n` elements and returns a pointer to it.
+    fn reserve(&mut self, n: usize) -> *mut T {
+        if self.buf.len() < n {
+            self.buf.resize_with(n, MaybeUninit::uninit);
+        }
 
-        s.rotate_left(l);
-        unsafe { buf_rotate(l, p, r, buffer) };
+        self.buf.as_mut_ptr() as *mut T
+    }
 
-        assert_eq!(vec, s);
+    /// Rotates with [`ptr_aux_rotate`], growing the buffer to `min(k, len - k)` elements.
+    pub fn rotate_aux(&mut self, s: &mut [T], k: usize) {
+        let (left, mid, right) = split_at_ptr(s, k, "rotate_aux");
 
-        unsafe { buf_rotate(r, p.sub(diff), l, buffer) };
+        if is_zst::<T>() {
+            return;
+        }
 
-        s.rotate_right(l);
-        assert_eq!(vec, s);
+        let buf = self.reserve(cmp::min(left, right));
+
+        // SAFETY: `[mid - left, mid + right)` is exactly `s` and the buffer fits the smallest
+        // side.
+        unsafe { ptr_aux_rotate_to(left, mid, right, buf) };
     }
 
-    fn test_correct(
-        rotate_f: unsafe fn(left: usize, mid: *mut usize, right: usize, buffer: &mut [usize]),
-    ) {
-        let mut buffer = Vec::<usize>::with_capacity(100_000);
+    /// Rotates with [`ptr_bridge_rotate`], growing the buffer to `min(|len - 2k|, k, len - k)`
+    /// elements.
+    pub fn rotate_bridge(&mut self, s: &mut [T], k: usize) {
+        let (left, mid, right) = split_at_ptr(s, k, "rotate_bridge");
 
-        // --empty--
-        case(rotate_f, 0, 0, buffer.as_mut_slice());
+        if is_zst::<T>() {
+            return;
+        }
 
-        // 1  2  3  4  5  6 (7  8  9)10 11 12 13 14 15
-        case(rotate_f, 15, 3, buffer.as_mut_slice());
+        let bridge = left.abs_diff(right);
+        let buf = self.reserve(cmp::min(bridge, cmp::min(left, right)));
 
-        // 1  2  3  4  5  6  7 (8) 9 10 11 12 13 14 15
-        case(rotate_f, 15, 1, buffer.as_mut_slice());
+        // SAFETY: `[mid - left, mid + right)` is exactly `s` and the buffer fits either the
+        // bridge or the smallest side, whichever is used.
+        unsafe {
+            if bridge < cmp::min(left, right) {
+                ptr_bridge_rotate_simple(left, mid, right, buf);
+            } else {
+                ptr_aux_rotate_to(left, mid, right, buf);
+            }
+        }
+    }
 
-        // 1  2  3  4  5 (6  7  8  9 10)11 12 13 14 15
-        case(rotate_f, 15, 5, buffer.as_mut_slice());
+    /// Rotates with [`ptr_trinity_rotate`] using the buffer as is: it only uses the buffer when
+    /// the moved part fits into it, so the buffer never grows.
+    pub fn rotate_trinity(&mut self, s: &mut [T], k: usize) {
+        let (left, mid, right) = split_at_ptr(s, k, "rotate_trinity");
 
-        // 1  2  3  4  5  6  7)(8  9 10 11 12 13 14
-        case(rotate_f, 14, 0, buffer.as_mut_slice());
+        if is_zst::<T>() {
+            return;
+        }
 
-        // 1  2  3  4 (5  6  7  8  9 10 11)12 13 14 15
-        case(rotate_f, 15, 7, buffer.as_mut_slice());
+        let capacity = self.capacity();
+        let buf = self.reserve(0);
 
-        // 1 (2  3  4  5  6  7  8  9 10 11 12 13 14)15
-        case(rotate_f, 15, 13, buffer.as_mut_slice());
+        // SAFETY: `[mid - left, mid + right)` is exactly `s` and `buf` holds `capacity` elements.
+        unsafe { ptr_trinity_rotate_to(left, mid, right, buf, capacity) };
+    }
+}
 
-        //(1  2  3  4  5  6  7  8  9 10 11 12 13 14 15)
-        case(rotate_f, 15, 15, buffer.as_mut_slice());
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::*;
+    use crate::*;
+
+    fn test_correct(
+        rotate: unsafe fn(left: usize, mid: *mut usize, right: usize, buffer: &mut [usize]),
+    ) {
+        let mut buffer = vec![0; 100_000];
 
-        //(1  2  3  4  5  6  7  8  9 10 11 12 13 14 15)
-        case(rotate_f, 100_000, 0, buffer.as_mut_slice());
+        test_correct_with(|l, p, r| unsafe { rotate(l, p, r, &mut buffer) });
     }
 
     #[test]
@@ -560,6 +1211,32 @@ mod tests {
         test_correct(ptr_aux_rotate::<usize>);
     }
 
+    #[test]
+    fn ptr_small_shift_rotate_correct() {
+        test_correct(|l, p, r, _| unsafe { ptr_small_shift_rotate::<usize>(l, p, r) });
+
+        for size in (0..=20usize).chain([100, 1000]) {
+            for l in (0..=5).chain(size.saturating_sub(5)..=size) {
+                if l > size {
+                    continue;
+                }
+
+                let mut v = seq(size);
+                let mut s = seq(size);
+
+                s.rotate_left(l);
+                unsafe { ptr_small_shift_rotate(l, v.as_mut_ptr().add(l), size - l) };
+
+                assert_eq!(v, s, "size = {size}, left = {l}");
+            }
+        }
+    }
+
+    #[test]
+    fn ptr_aux_rotate_stack_correct() {
+        test_correct(|l, p, r, _| unsafe { ptr_aux_rotate_stack::<usize>(l, p, r) });
+    }
+
     #[test]
     fn ptr_naive_aux_rotate_correct() {
         test_correct(ptr_naive_aux_rotate::<usize>);
@@ -570,8 +1247,319 @@ mod tests {
         test_correct(ptr_bridge_rotate::<usize>);
     }
 
+    #[test]
+    fn ptr_bridge_rotate_simple_correct() {
+        for size in (0..=40usize).chain([100, 1000]) {
+            for l in 0..=size {
+                let r = size - l;
+                let bridge = l.abs_diff(r);
+
+                if cmp::min(l, r) <= bridge && cmp::min(l, r) > 2 {
+                    continue;
+                }
+
+                let mut buffer = vec![0; bridge];
+                let mut v = seq(size);
+                let mut s = seq(size);
+
+                s.rotate_left(l);
+                unsafe {
+                    ptr_bridge_rotate_simple(l, v.as_mut_ptr().add(l), r, buffer.as_mut_ptr())
+                };
+
+                assert_eq!(v, s, "size = {size}, left = {l}");
+            }
+        }
+    }
+
+    #[test]
+    fn ptr_aux_rotate_checked_correct() {
+        test_correct(|l, p, r, b| unsafe { ptr_aux_rotate_checked::<usize>(l, p, r, b).unwrap() });
+
+        for size in [0, 1, 5, 16, 100] {
+            for l in 0..=size {
+                let min = cmp::min(l, size - l);
+
+                let mut v = seq(size);
+                let mut s = seq(size);
+                s.rotate_left(l);
+
+                let mut buffer = vec![0; min];
+                assert_eq!(
+                    unsafe {
+                        ptr_aux_rotate_checked(l, v.as_mut_ptr().add(l), size - l, &mut buffer)
+                    },
+                    Ok(())
+                );
+                assert_eq!(v, s, "size = {size}, left = {l}");
+
+                if min > 0 {
+                    let mut v = seq(size);
+                    let mut buffer = vec![0; min - 1];
+
+                    assert_eq!(
+                        unsafe {
+                            ptr_aux_rotate_checked(l, v.as_mut_ptr().add(l), size - l, &mut buffer)
+                        },
+                        Err(BufferTooSmall {
+                            required: min,
+                            len: min - 1
+                        })
+                    );
+                    assert_eq!(v, seq(size), "untouched: size = {size}, left = {l}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ptr_helix_rotate_aux_correct() {
+        test_correct(ptr_helix_rotate_aux::<usize>);
+        test_correct(|l, p, r, _| unsafe { ptr_helix_rotate_aux::<usize>(l, p, r, &mut []) });
+        test_correct(|l, p, r, b| unsafe { ptr_helix_rotate_aux::<usize>(l, p, r, &mut b[..1]) });
+    }
+
     #[test]
     fn ptr_trinity_rotate_correct() {
         test_correct(ptr_trinity_rotate::<usize>);
+        test_correct(|l, p, r, _| unsafe { ptr_trinity_rotate::<usize>(l, p, r, &mut []) });
+        test_correct(|l, p, r, b| unsafe { ptr_trinity_rotate::<usize>(l, p, r, &mut b[..5]) });
+    }
+
+    #[test]
+    fn ptr_rotate_large_t_correct() {
+        test_correct(ptr_rotate_large_t::<usize>);
+
+        for buf_len in [0, 1, 3, 10, 50] {
+            let mut buffer = vec![[0; 5]; buf_len];
+
+            sweep(|size, l| {
+                let mut v: Vec<[usize; 5]> = (0..size).map(|i| [i; 5]).collect();
+                let mut s = v.clone();
+
+                s.rotate_left(l);
+                unsafe { ptr_rotate_large_t(l, v.as_mut_ptr().add(l), size - l, &mut buffer) };
+
+                assert_eq!(v, s, "size = {size}, left = {l}, buffer = {buf_len}");
+            });
+        }
+    }
+
+    #[test]
+    fn ptr_contrev_rotate_buffered_correct() {
+        test_correct(ptr_contrev_rotate_buffered::<usize>);
+
+        for buf_len in [1, 2, 3, 5, 8, 32] {
+            let mut buffer = vec![0; buf_len];
+
+            sweep_rotate(|l, p, r| unsafe { ptr_contrev_rotate_buffered(l, p, r, &mut buffer) });
+        }
+    }
+
+    #[test]
+    fn ptr_trinity_rotate_inplace_correct() {
+        test_correct(|l, p, r, _| unsafe { ptr_trinity_rotate_inplace::<usize>(l, p, r) });
+
+        for size in (0..=100).chain([1000]) {
+            for l in 0..=size {
+                let mut v = seq(size);
+                let mut s = seq(size);
+
+                s.rotate_left(l);
+                unsafe { ptr_trinity_rotate_inplace(l, v.as_mut_ptr().add(l), size - l) };
+
+                assert_eq!(v, s, "size = {size}, left = {l}");
+
+                let mut v: Vec<[usize; 5]> = (0..size).map(|i| [i; 5]).collect();
+                let mut s = v.clone();
+
+                s.rotate_left(l);
+                unsafe { ptr_trinity_rotate_inplace(l, v.as_mut_ptr().add(l), size - l) };
+
+                assert_eq!(v, s, "size = {size}, left = {l}, [usize; 5]");
+            }
+        }
+    }
+
+    #[test]
+    fn ptr_bridge_rotate_with_policy_correct() {
+        test_correct(|l, p, r, b| unsafe {
+            ptr_bridge_rotate_with_policy::<usize>(l, p, r, b, BridgePolicy::AlwaysBridge)
+        });
+        test_correct(|l, p, r, b| unsafe {
+            ptr_bridge_rotate_with_policy::<usize>(l, p, r, b, BridgePolicy::AlwaysAux)
+        });
+        test_correct(|l, p, r, b| unsafe {
+            ptr_bridge_rotate_with_policy::<usize>(l, p, r, b, BridgePolicy::Auto { min_bridge: 0 })
+        });
+        test_correct(|l, p, r, b| unsafe {
+            ptr_bridge_rotate_with_policy::<usize>(l, p, r, b, BridgePolicy::Auto { min_bridge: 4 })
+        });
+
+        let policies = [
+            BridgePolicy::AlwaysBridge,
+            BridgePolicy::AlwaysAux,
+            BridgePolicy::Auto { min_bridge: 0 },
+            BridgePolicy::Auto { min_bridge: 3 },
+            BridgePolicy::Auto {
+                min_bridge: usize::MAX,
+            },
+        ];
+
+        for policy in policies {
+            for size in 0..=40 {
+                for l in 0..=size {
+                    let mut v = seq(size);
+                    let mut s = seq(size);
+                    let mut buffer = vec![0; size];
+
+                    s.rotate_left(l);
+                    unsafe {
+                        ptr_bridge_rotate_with_policy(
+                            l,
+                            v.as_mut_ptr().add(l),
+                            size - l,
+                            &mut buffer,
+                            policy,
+                        )
+                    };
+
+                    assert_eq!(v, s, "size = {size}, left = {l}, {policy:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn rotate_buffer_correct() {
+        type Method = fn(&mut RotateBuffer<usize>, &mut [usize], usize);
+
+        let methods: [Method; 3] = [
+            |b, s, k| b.rotate_aux(s, k),
+            |b, s, k| b.rotate_bridge(s, k),
+            |b, s, k| b.rotate_trinity(s, k),
+        ];
+
+        for rotate in methods {
+            let mut buffer = RotateBuffer::with_capacity(4);
+
+            for len in (0..=40usize).chain([100, 1000, 10_000]) {
+                for k in [0, len / 3, len / 2, len - len / 3, len] {
+                    let mut v = seq(len);
+                    let mut s = seq(len);
+
+                    rotate(&mut buffer, &mut v, k);
+                    s.rotate_left(k);
+
+                    assert_eq!(v, s, "len = {len}, k = {k}");
+                }
+            }
+        }
+
+        let mut buffer = RotateBuffer::new();
+        buffer.rotate_aux(&mut seq(10_000), 4_000);
+        assert_eq!(buffer.capacity(), 4_000);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "alloc")]
+    fn rotate_buffer_out_of_bounds() {
+        RotateBuffer::new().rotate_aux(&mut seq(15), 16);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "buffer length = 6 < 7")]
+    fn ptr_aux_rotate_small_buffer() {
+        let (_v, (l, p, r)) = prepare(15, 1);
+        unsafe { ptr_aux_rotate(l, p, r, &mut [0; 6]) };
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "buffer length = 2 < 3")]
+    fn ptr_bridge_rotate_small_buffer() {
+        let (_v, (l, p, r)) = prepare(15, 3);
+        unsafe { ptr_bridge_rotate(l, p, r, &mut [0; 2]) };
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn ptr_rotate_auto_buffer_correct() {
+        sweep_rotate(|l, p, r| unsafe { ptr_rotate_auto_buffer(l, p, r) });
+    }
+
+    #[test]
+    fn ptr_aux_rotate_adjacent_buffer() {
+        for size in [0, 1, 5, 16, 100] {
+            for l in 0..=size {
+                let min = cmp::min(l, size - l);
+
+                let mut s = seq(size);
+                s.rotate_left(l);
+
+                // scratch space right after the range
+                let mut v = seq(size + min);
+                let (range, buffer) = v.split_at_mut(size);
+                unsafe { ptr_aux_rotate(l, range.as_mut_ptr().add(l), size - l, buffer) };
+                assert_eq!(v[..size], s, "after: size = {size}, left = {l}");
+
+                // scratch space right before the range
+                let mut v: Vec<usize> = vec![0; min].into_iter().chain(seq(size)).collect();
+                let (buffer, range) = v.split_at_mut(min);
+                unsafe { ptr_aux_rotate(l, range.as_mut_ptr().add(l), size - l, buffer) };
+                assert_eq!(v[min..], s, "before: size = {size}, left = {l}");
+            }
+        }
+    }
+
+    #[test]
+    fn ptr_aux_rotate_adjacent_buffer_drop() {
+        use crate::test_utils::counted;
+        use std::cell::Cell;
+
+        for (size, l) in [(15, 4), (15, 11), (100, 30), (100, 50)] {
+            let min = cmp::min(l, size - l);
+            let live = Cell::new(0);
+
+            // scratch space is the spare capacity right after the range
+            let mut v = counted(size, &live);
+            v.reserve_exact(min);
+
+            unsafe {
+                let p = v.as_mut_ptr();
+                let buffer = core::slice::from_raw_parts_mut(p.add(size), min);
+
+                ptr_aux_rotate(l, p.add(l), size - l, buffer);
+            }
+            assert_eq!(live.get(), size as isize, "size = {size}, left = {l}");
+
+            let mut s: Vec<usize> = (0..size).collect();
+            s.rotate_left(l);
+            assert!(v.iter().map(|c| c.0).eq(s), "size = {size}, left = {l}");
+
+            drop(v);
+            assert_eq!(live.get(), 0, "size = {size}, left = {l}");
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "ptr_aux_rotate: buffer aliases the rotated range")]
+    fn ptr_aux_rotate_aliased_buffer() {
+        let (_v, (l, p, r)) = prepare(15, 3);
+        let buffer = unsafe { core::slice::from_raw_parts_mut(p.add(1), cmp::min(l, r)) };
+        unsafe { ptr_aux_rotate(l, p, r, buffer) };
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "buffer aliases the rotated range")]
+    fn ptr_trinity_rotate_aliased_buffer() {
+        let (_v, (l, p, r)) = prepare(15, 3);
+        let buffer = unsafe { core::slice::from_raw_parts_mut(p, 3) };
+        unsafe { ptr_trinity_rotate(l, p, r, buffer) };
     }
 }
@@ -0,0 +1,394 @@
+/*
+Copyright (C) 2023 Valentin Vasilev (3volny@gmail.com).
+*/
+
+/*
+Permission is hereby granted, free of charge, to any person obtaining
+a copy of this software and associated documentation files (the
+"Software"), to deal in the Software without restriction, including
+without limitation the rights to use, copy, modify, merge, publish,
+distribute, sublicense, and/or sell copies of the Software, and to
+permit persons to whom the Software is furnished to do so, subject to
+the following conditions:
+
+The above copyright notice and this permission notice shall be
+included in all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY
+CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,
+TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE
+SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use core::ops::{BitOr, Shl, Shr, Sub};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Unsigned integer types supported by [`binary`].
+///
+/// This trait is sealed and is implemented for `u16`, `u32`, `u64`, `u128` and `usize`.
+pub trait Unsigned:
+    private::Sealed
+    + Copy
+    + Ord
+    + BitOr<Output = Self>
+    + Sub<Output = Self>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+{
+    /// The `0` value of the type.
+    const ZERO: Self;
+
+    /// Returns the number of trailing zeros in the binary representation of `self`.
+    fn trailing_zeros(self) -> u32;
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+
+            impl Unsigned for $t {
+                const ZERO: Self = 0;
+
+                #[inline(always)]
+                fn trailing_zeros(self) -> u32 {
+                    <$t>::trailing_zeros(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned!(u16, u32, u64, u128, usize);
+
+/// # Binary GCD
+///
+/// Computes the greatest common divisor of `a` and `b` using Stein's binary algorithm.
+///
+/// ## Zero handling
+///
+/// `gcd(a, 0) = a` and `gcd(0, b) = b`, so if either operand is `0` the other one is returned. In
+/// particular, `binary(0, 0) == 0`.
+///
+/// ## Algorithm
+///
+/// Common factors of two are removed first (`trailing_zeros` of `a | b`). Then `a` is made odd
+/// and the larger odd value is repeatedly replaced by the difference, stripped of its factors of
+/// two, until it becomes `0`. The result is the remaining odd value shifted back by the common
+/// power of two.
+///
+/// ## Example
+///
+/// ```rust
+/// use rust_rotations::binary;
+///
+/// assert_eq!(binary(12u32, 18), 6);
+/// assert_eq!(binary(0u64, 7), 7);
+/// ```
+pub fn binary<T: Unsigned>(mut a: T, mut b: T) -> T {
+    if a == T::ZERO {
+        return b;
+    }
+    if b == T::ZERO {
+        return a;
+    }
+
+    let shift = (a | b).trailing_zeros();
+
+    a = a >> a.trailing_zeros();
+
+    loop {
+        b = b >> b.trailing_zeros();
+
+        if a > b {
+            core::mem::swap(&mut a, &mut b);
+        }
+
+        b = b - a;
+
+        if b == T::ZERO {
+            return a << shift;
+        }
+    }
+}
+
+/// # Binary GCD for `usize`
+///
+/// [`binary`] specialized for `usize`. If either operand is `0` the other one is returned.
+///
+/// ## Example
+///
+/// ```rust
+/// use rust_rotations::binary_usize;
+///
+/// assert_eq!(binary_usize(12, 18), 6);
+/// assert_eq!(binary_usize(0, 7), 7);
+/// ```
+#[inline]
+pub fn binary_usize(a: usize, b: usize) -> usize {
+    binary(a, b)
+}
+
+/// # Const GCD
+///
+/// Computes the greatest common divisor of `a` and `b` in a `const` context using the Euclidean
+/// algorithm. If either operand is `0` the other one is returned.
+///
+/// ## Example
+///
+/// ```rust
+/// use rust_rotations::const_gcd_usize;
+///
+/// const L: usize = 12;
+/// const R: usize = 18;
+/// const BLOCK: usize = const_gcd_usize(L, R);
+///
+/// assert_eq!(BLOCK, 6);
+/// ```
+pub const fn const_gcd_usize(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+
+    a
+}
+
+/// # LCM
+///
+/// Computes the least common multiple of `a` and `b` as `a / gcd(a, b) * b`, dividing first so
+/// that no intermediate value is larger than the result.
+///
+/// Returns `Some(0)` if either operand is `0` and `None` if the result does not fit into `usize`.
+///
+/// ## Example
+///
+/// ```rust
+/// use rust_rotations::lcm;
+///
+/// assert_eq!(lcm(4, 6), Some(12));
+/// assert_eq!(lcm(0, 6), Some(0));
+/// assert_eq!(lcm(usize::MAX, 2), None);
+/// ```
+pub fn lcm(a: usize, b: usize) -> Option<usize> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / binary_usize(a, b)).checked_mul(b)
+}
+
+/// # Rotation cycles
+///
+/// Returns the number of cycles of the permutation performed by a rotation of `left + right`
+/// elements by `left`, i.e. `gcd(left + right, right)`. Each cycle has `(left + right) /
+/// cycles` elements.
+///
+/// The cycle-following rotations ([`ptr_direct_rotate`](crate::ptr_direct_rotate),
+/// [`ptr_juggling_rotate`](crate::ptr_juggling_rotate)) follow one cycle at a time, and every
+/// cycle strides over the whole range: for large ranges each cycle is a separate pass over memory
+/// (see the `GcdCycles` benchmark). A no-op rotation (`left == 0` or `right == 0`) has
+/// `left + right` cycles of one element.
+///
+/// Computed as `gcd(left, right)`, which is the same and can't overflow.
+///
+/// ## Example
+///
+/// ```rust
+/// use rust_rotations::rotation_cycles;
+///
+/// assert_eq!(rotation_cycles(4, 6), 2);
+/// assert_eq!(rotation_cycles(3, 7), 1);
+/// assert_eq!(rotation_cycles(0, 5), 5);
+/// ```
+pub fn rotation_cycles(left: usize, right: usize) -> usize {
+    binary_usize(left, right)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn euclid(a: usize, b: usize) -> usize {
+        if b == 0 {
+            a
+        } else {
+            euclid(b, a % b)
+        }
+    }
+
+    #[test]
+    fn binary_usize_coprime() {
+        assert_eq!(binary_usize(1, 1), 1);
+        assert_eq!(binary_usize(3, 7), 1);
+        assert_eq!(binary_usize(15, 4), 1);
+        assert_eq!(binary_usize(17, 3), 1);
+    }
+
+    #[test]
+    fn binary_usize_equal() {
+        for a in 1..100 {
+            assert_eq!(binary_usize(a, a), a);
+        }
+    }
+
+    #[test]
+    fn binary_usize_powers_of_two() {
+        assert_eq!(binary_usize(8, 32), 8);
+        assert_eq!(binary_usize(1 << 20, 1 << 10), 1 << 10);
+        assert_eq!(binary_usize(48, 64), 16);
+        assert_eq!(binary_usize(1 << (usize::BITS - 1), 6), 2);
+    }
+
+    #[test]
+    fn binary_usize_zero() {
+        assert_eq!(binary_usize(0, 0), 0);
+        assert_eq!(binary_usize(0, 9), 9);
+        assert_eq!(binary_usize(9, 0), 9);
+        assert_eq!(binary_usize(usize::MAX, 0), usize::MAX);
+    }
+
+    #[test]
+    fn binary_usize_correct() {
+        for a in 0..200 {
+            for b in 0..200 {
+                assert_eq!(binary_usize(a, b), euclid(a, b), "gcd({a}, {b})");
+            }
+        }
+    }
+
+    #[test]
+    fn rotation_cycles_correct() {
+        for len in 0..=60 {
+            for left in 0..=len {
+                // follow the cycles of `i -> (i + len - left) % len`
+                let mut seen = vec![false; len];
+                let mut cycles = 0;
+
+                for s in 0..len {
+                    if !seen[s] {
+                        cycles += 1;
+
+                        let mut i = s;
+                        while !seen[i] {
+                            seen[i] = true;
+                            i = (i + len - left) % len;
+                        }
+                    }
+                }
+
+                assert_eq!(
+                    rotation_cycles(left, len - left),
+                    cycles,
+                    "len = {len}, left = {left}"
+                );
+            }
+        }
+
+        assert_eq!(rotation_cycles(usize::MAX, usize::MAX), usize::MAX);
+    }
+
+    #[test]
+    fn lcm_zero() {
+        assert_eq!(lcm(0, 0), Some(0));
+        assert_eq!(lcm(0, 5), Some(0));
+        assert_eq!(lcm(5, 0), Some(0));
+        assert_eq!(lcm(0, usize::MAX), Some(0));
+    }
+
+    #[test]
+    fn lcm_correct() {
+        assert_eq!(lcm(1, 1), Some(1));
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(9, 6), Some(18));
+        assert_eq!(lcm(7, 7), Some(7));
+        assert_eq!(lcm(3, 5), Some(15));
+
+        for a in 1..100 {
+            for b in 1..100 {
+                assert_eq!(lcm(a, b), Some(a * b / euclid(a, b)), "lcm({a}, {b})");
+            }
+        }
+    }
+
+    #[test]
+    fn lcm_overflow() {
+        assert_eq!(lcm(usize::MAX, 1), Some(usize::MAX));
+        assert_eq!(lcm(usize::MAX, usize::MAX), Some(usize::MAX));
+        assert_eq!(lcm(usize::MAX, 2), None);
+        assert_eq!(lcm(usize::MAX - 1, usize::MAX), None);
+
+        let half = 1 << (usize::BITS - 1);
+        assert_eq!(lcm(half, 2), Some(half));
+        assert_eq!(lcm(half, 3), None);
+    }
+
+    macro_rules! test_binary {
+        ($($name:ident: $t:ty),*) => {
+            $(
+                #[test]
+                fn $name() {
+                    assert_eq!(binary::<$t>(0, 0), 0);
+                    assert_eq!(binary::<$t>(0, 9), 9);
+                    assert_eq!(binary::<$t>(9, 0), 9);
+                    assert_eq!(binary::<$t>(3, 7), 1);
+                    assert_eq!(binary::<$t>(48, 64), 16);
+                    assert_eq!(binary::<$t>(<$t>::MAX, <$t>::MAX), <$t>::MAX);
+                    assert_eq!(binary::<$t>(1 << (<$t>::BITS - 1), 6), 2);
+
+                    for a in 0..100 {
+                        for b in 0..100 {
+                            assert_eq!(
+                                binary::<$t>(a, b) as usize,
+                                euclid(a as usize, b as usize),
+                                "gcd({a}, {b})"
+                            );
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    test_binary!(
+        binary_u16_correct: u16,
+        binary_u32_correct: u32,
+        binary_u64_correct: u64,
+        binary_u128_correct: u128,
+        binary_usize_generic_correct: usize
+    );
+
+    #[test]
+    fn const_gcd_usize_correct() {
+        const PAIRS: [(usize, usize); 10] = [
+            (0, 0),
+            (0, 9),
+            (9, 0),
+            (3, 7),
+            (12, 18),
+            (48, 64),
+            (17, 3),
+            (15, 4),
+            (1 << 20, 1 << 10),
+            (usize::MAX, usize::MAX),
+        ];
+
+        const _: () = {
+            assert!(const_gcd_usize(12, 18) == 6);
+            assert!(const_gcd_usize(0, 9) == 9);
+            assert!(const_gcd_usize(9, 0) == 9);
+            assert!(const_gcd_usize(0, 0) == 0);
+        };
+
+        for (a, b) in PAIRS {
+            assert_eq!(const_gcd_usize(a, b), binary_usize(a, b), "gcd({a}, {b})");
+        }
+    }
+}
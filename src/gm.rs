@@ -23,9 +23,13 @@ TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE
 SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 */
 
+use crate::is_zst;
+use crate::ptr_direct_rotate;
 use crate::ptr_edge_rotate;
-use std::mem::MaybeUninit;
-use std::ptr;
+use crate::{swap_backward, swap_forward, swap_halves};
+use core::cmp;
+use core::mem::MaybeUninit;
+use core::ptr;
 
 /// # Gries-Mills rotation (recursive)
 ///
@@ -78,6 +82,15 @@ use std::ptr;
 /// [10 ~~~~~~~~~~~ 15: 1 ~~~ 3* 4  .  .  .  .  9]
 /// ```
 pub unsafe fn ptr_griesmills_rotate_rec<T>(left: usize, mid: *mut T, right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    if left == right {
+        swap_halves(mid.sub(left), mid, left);
+        return;
+    }
+
     if right <= 2 || left <= 2 {
         ptr_edge_rotate(left, mid, right);
         return;
@@ -143,6 +156,19 @@ pub unsafe fn ptr_griesmills_rotate_rec<T>(left: usize, mid: *mut T, right: usiz
 /// [10 ~~~~~~~~~~~ 15: 1  .  3* 4  .  .  .  .  9]
 /// ```
 pub unsafe fn ptr_griesmills_rotate<T>(mut left: usize, mut mid: *mut T, mut right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    if left == 0 || right == 0 {
+        return;
+    }
+
+    if left == right {
+        swap_halves(mid.sub(left), mid, left);
+        return;
+    }
+
     loop {
         if left <= right {
             if left <= 2 {
@@ -167,6 +193,227 @@ pub unsafe fn ptr_griesmills_rotate<T>(mut left: usize, mut mid: *mut T, mut rig
     }
 }
 
+/// # Gries-Mills rotation (Optimized)
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// Same as [`ptr_griesmills_rotate`], but the sides are swapped until the smallest one reaches
+/// exactly `1` element, the worst case of *Gries-Mills*. The rest is then finished with a single
+/// [`ptr_direct_rotate`](crate::ptr_direct_rotate) over the remaining range instead of more
+/// rounds.
+///
+/// Note that for a side of `1` element [`ptr_direct_rotate`](crate::ptr_direct_rotate) itself
+/// puts it aside and shifts the other side with [`ptr_edge_rotate`](crate::ptr_edge_rotate), so
+/// every remaining element is moved once.
+///
+/// For example, `left = 25, right = 26`: one swap of `25` elements leaves `left = 25, right = 1`,
+/// which is finished with a *Direct rotation*.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+pub unsafe fn ptr_griesmills_rotate_opt<T>(mut left: usize, mut mid: *mut T, mut right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    while left > 0 && right > 0 {
+        if left == 1 || right == 1 {
+            ptr_direct_rotate(left, mid, right);
+            return;
+        }
+
+        if left <= right {
+            let start = mid.sub(left);
+            ptr::swap_nonoverlapping(start, mid, left);
+            mid = mid.add(left);
+            right -= left;
+        } else {
+            ptr::swap_nonoverlapping(mid, mid.sub(right), right);
+            mid = mid.sub(right);
+            left -= right;
+        }
+    }
+}
+
+/// # Grail (Gries-Mills + *swap_backward*) rotation
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes
+/// the first element. Equivalently, rotates the range `left` elements to the left
+/// or `right` elements to the right.
+///
+/// ## Algorithm
+///
+/// 1. Swap the smallest side with its 𝑠ℎ𝑎𝑑𝑜𝑤, moving right if it is the l-side (`swap_forward`)
+///    and left if it is the r-side (`swap_backward`);
+/// 2. repeat while the same side stays the smallest;
+/// 3. rotate smaller array.
+///
+/// "The grail rotation from the Holy *Grail Sort Project* is *Gries-Mills* derived
+/// and tries to improve locality by shifting memory either left or right depending on which
+/// side it's swapped from.
+///
+/// In addition it performs an auxiliary rotation on stack memory when the smallest side reaches
+/// a size of `1` element, which is the worst case for the *Gries-Mills rotation*. The flow diagram
+/// is identical to that of *Gries-Mills*, but due to memory being shifted from the right the
+/// visualization differs." <<https://github.com/scandum/rotate>>
+///
+/// Here `ptr_edge_rotate` plays the role of the auxiliary rotation, it is used once the smallest
+/// side has `<= 2` elements.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+///
+/// ## Example
+///
+/// ```text
+///                  𝑠ℎ𝑎𝑑𝑜𝑤    mid
+///           left = 9         |    right = 6
+/// [ 1  2  3  4  5  6: 7  8  9*10 11 12 13 14 15]  // swap r-side and shadow <--
+///            └──────────────┴/\┴──────────────┘
+///            ┌──────────────┬\~┬──────────────┐
+/// [ 1  .  3 10  .  .  .  . 15  4 ~~~~~~~~~~~~ 9]
+///
+///    l = 3     𝑠ℎ. r = 6
+/// [ 1  .  3*10  . 12:13  . 15] 4  .  .  .  .  9   // swap new l-side and new shadow -->
+///   └─────┴/\┴─────┘
+///   ┌─────┬~/┬─────┐
+/// [10 ~~ 12  1  .  3 13  . 15] 4  .  .  .  .  9
+///
+///            l = 3    r = 3
+///  10 ~~ 12[ 1  .  3;13  . 15] 4  .  .  .  .  9   // swap equal -->
+///           └──────┴/\┴─────┘
+///           ┌──────┬~~┬─────┐
+///  10 ~~ 12[13 ~~~ 15 1 ~~~ 3] 4  .  .  .  .  9
+///
+/// [10 ~~~~~~~~~~~ 15: 1 ~~~ 3* 4 ~~~~~~~~~~~~ 9]
+/// ```
+pub unsafe fn ptr_grail_rotate<T>(mut left: usize, mid: *mut T, mut right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    if left == right {
+        swap_halves(mid.sub(left), mid, left);
+        return;
+    }
+
+    let mut start = mid.sub(left);
+    let mut min = cmp::min(left, right);
+
+    while min > 2 {
+        if left <= right {
+            // -->
+            loop {
+                swap_forward(start, start.add(left), left);
+
+                start = start.add(left);
+                right -= left;
+
+                if left > right {
+                    break;
+                }
+            }
+
+            min = right;
+        } else {
+            // <--
+            loop {
+                swap_backward(start.add(left - right), start.add(left), right);
+
+                left -= right;
+
+                if right > left {
+                    break;
+                }
+            }
+
+            min = left;
+        }
+    }
+
+    ptr_edge_rotate(left, start.add(left), right);
+}
+
+/// # Swap rotation
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// Same loop as [`ptr_grail_rotate`], but built only on [`swap_forward`], [`swap_backward`] and
+/// `ptr::swap_nonoverlapping`: the smallest side is swapped across the other one until the sides
+/// are equal, including the last `1` or `2` elements, which *Grail* hands to
+/// [`ptr_edge_rotate`](crate::ptr_edge_rotate).
+///
+/// It makes more swaps than [`ptr_direct_rotate`](crate::ptr_direct_rotate), but never holds an
+/// element in a temporary across the loop. Usually `2`-`3` times slower than
+/// [`ptr_griesmills_rotate`] and [`ptr_piston_rotate`](crate::ptr_piston_rotate) (`bench_gm`),
+/// up to `8` times when a side has `1` or `2` elements.
+///
+/// ## Swaps only
+///
+/// Unlike the other rotations, every element is moved only by swapping two slots of the range
+/// (`core::mem::swap` and `ptr::swap_nonoverlapping`); there are no `ptr::read`, `ptr::write`,
+/// `ptr::copy` or buffers. Between two swaps every element is in exactly one slot of the range,
+/// so nothing is duplicated, lost or dropped, which the tests check with a drop-counting type.
+///
+/// Whether an element is ever read into a register is up to the swap implementations and the
+/// compiler, so "never read outside of the range" can't be checked or promised. A swap is still a
+/// bitwise move, so this does not make it sound to move pinned values either.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+///
+/// ## Example
+///
+/// ```text
+///          mid
+///  left = 3|     right = 8
+/// [ a  b  c* 1  2  3  4  5  6  7  8]  // swap -->
+/// [ 1 ~~~ 3  a  b  c* 4  5  6  7  8]  // swap -->
+/// [ 1 ~~~~~~~~~~~~ 6  a  b  c* 7  8]  // swap <--
+/// [ 1 ~~~~~~~~~~~~ 6  a* 7  8  b  c]  // swap -->
+/// [ 1 ~~~~~~~~~~~~~~~ 7  a* 8  b  c]  // swap equal
+/// [ 1 ~~~~~~~~~~~~~~~~~~ 8: a  b  c]
+/// ```
+#[doc(alias = "ptr_rotate_swap_only")]
+pub unsafe fn ptr_swap_rotate<T>(mut left: usize, mid: *mut T, mut right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    let mut start = mid.sub(left);
+
+    while left > 0 && right > 0 {
+        if left == right {
+            ptr::swap_nonoverlapping(start, start.add(left), left);
+            return;
+        }
+
+        if left < right {
+            // -->
+            swap_forward(start, start.add(left), left);
+
+            start = start.add(left);
+            right -= left;
+        } else {
+            // <--
+            swap_backward(start.add(left - right), start.add(left), right);
+
+            left -= right;
+        }
+    }
+}
+
 /// # Drill rotation
 ///
 /// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
@@ -203,14 +450,56 @@ pub unsafe fn ptr_griesmills_rotate<T>(mut left: usize, mut mid: *mut T, mut rig
 ///   1 ~~~~~~~~~~~~ 6[ a *7 :8] b  c   // ptr_edge_rotate
 ///   1 ~~~ 3* 4 ~~~ 6  7  8 :a  b  c
 /// ```
-pub unsafe fn ptr_drill_rotate<T>(mut left: usize, mid: *mut T, mut right: usize) {
+pub unsafe fn ptr_drill_rotate<T>(left: usize, mid: *mut T, right: usize) {
+    ptr_drill_rotate_thresh(left, mid, right, 2);
+}
+
+/// # Drill rotation (with threshold)
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// Same as [`ptr_drill_rotate`], but the drill stops and hands off to
+/// [`ptr_edge_rotate`](crate::ptr_edge_rotate) once a side is `<= edge_threshold`, instead of
+/// `<= 2`.
+///
+/// A larger threshold stops earlier: fewer short swap loops and their branch mispredictions, but
+/// the larger side is shifted by `ptr_edge_rotate` (which itself uses
+/// [`stable_ptr_rotate`](crate::stable_ptr_rotate) for sides larger than `2`). A smaller one drills
+/// longer, which pays off when shifting large `T` is expensive.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+pub unsafe fn ptr_drill_rotate_thresh<T>(
+    mut left: usize,
+    mid: *mut T,
+    mut right: usize,
+    edge_threshold: usize,
+) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    if left == 0 || right == 0 {
+        return;
+    }
+
+    if left == right {
+        swap_halves(mid.sub(left), mid, left);
+        return;
+    }
+
     let mut mid = mid.cast::<MaybeUninit<T>>();
 
     let mut start = mid.sub(left);
     let mut end = mid.add(right);
     let mut s;
 
-    while left > 2 {
+    while left > edge_threshold {
         if left <= right {
             // -->
             let old_r = right;
@@ -225,7 +514,7 @@ pub unsafe fn ptr_drill_rotate<T>(mut left: usize, mid: *mut T, mut right: usize
                 // SAFETY: By precondition, `i` is in-bounds because it's below `count`
                 let y = unsafe { &mut *mid.add(i) };
 
-                std::mem::swap(&mut *x, &mut *y);
+                core::mem::swap(&mut *x, &mut *y);
             }
 
             mid = mid.add(s);
@@ -233,7 +522,7 @@ pub unsafe fn ptr_drill_rotate<T>(mut left: usize, mid: *mut T, mut right: usize
         }
 
         // <--
-        if right <= 2 {
+        if right <= edge_threshold {
             break;
         }
 
@@ -253,110 +542,73 @@ pub unsafe fn ptr_drill_rotate<T>(mut left: usize, mid: *mut T, mut right: usize
             // SAFETY: By precondition, `i` is in-bounds because it's below `count`
             let y = unsafe { &mut *y.sub(i) };
 
-            std::mem::swap(&mut *x, &mut *y);
+            core::mem::swap(&mut *x, &mut *y);
         }
 
         mid = mid.sub(s);
         end = end.sub(s);
     }
 
-    if left <= 2 || right <= 2 {
+    if left <= edge_threshold || right <= edge_threshold {
         ptr_edge_rotate(left, mid, right);
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::test_utils::*;
     use crate::*;
 
-    fn div(s: usize, diff: usize) -> (usize, usize) {
-        assert!(s >= diff);
-        assert!(s % 2 == diff % 2);
-
-        let r = s / 2 - diff / 2;
-
-        (s - r, r)
-    }
-
-    fn seq(size: usize) -> Vec<usize> {
-        let mut v = vec![0; size];
-        for i in 0..size {
-            v[i] = i + 1;
-        }
-        v
-    }
-
-    fn prepare(size: usize, diff: usize) -> (Vec<usize>, (usize, *mut usize, usize)) {
-        let (l, r) = div(size, diff);
-        let mut v = seq(size);
-
-        unsafe {
-            let p = &v[..].as_mut_ptr().add(l);
-            (v, (l, p.clone(), r))
-        }
+    #[test]
+    fn ptr_griesmills_rotate_rec_correct() {
+        test_correct(ptr_griesmills_rotate_rec::<usize>);
     }
 
-    fn case(
-        rotate: unsafe fn(left: usize, mid: *mut usize, right: usize),
-        size: usize,
-        diff: usize,
-    ) {
-        let (vec, (l, p, r)) = prepare(size, diff);
-
-        let mut s = seq(size);
-
-        s.rotate_left(l);
-        unsafe { rotate(l, p, r) };
-
-        assert_eq!(vec, s);
-
-        unsafe { rotate(r, p.sub(diff), l) };
-
-        s.rotate_right(l);
-        assert_eq!(vec, s);
+    #[test]
+    fn ptr_griesmills_rotate_correct() {
+        test_correct(ptr_griesmills_rotate::<usize>);
     }
 
-    fn test_correct(rotate_f: unsafe fn(left: usize, mid: *mut usize, right: usize)) {
-        // --empty--
-        case(rotate_f, 0, 0);
-
-        // 1  2  3  4  5  6 (7  8  9)10 11 12 13 14 15
-        case(rotate_f, 15, 3);
-
-        // 1  2  3  4  5 (6  7  8  9 10)11 12 13 14 15
-        case(rotate_f, 15, 5);
-
-        // 1  2  3  4  5  6  7 (8) 9 10 11 12 13 14 15
-        case(rotate_f, 15, 1);
-
-        // 1  2  3  4  5  6  7)(8  9 10 11 12 13 14
-        case(rotate_f, 14, 0);
-
-        // 1  2  3  4 (5  6  7  8  9 10 11)12 13 14 15
-        case(rotate_f, 15, 7);
+    #[test]
+    fn ptr_griesmills_rotate_opt_correct() {
+        test_correct(ptr_griesmills_rotate_opt::<usize>);
 
-        // 1 (2  3  4  5  6  7  8  9 10 11 12 13 14)15
-        case(rotate_f, 15, 13);
+        for size in [30, 100, 1000] {
+            for l in (0..=size).filter(|l| l % 7 == 0 || *l < 5 || *l > size - 5) {
+                let mut v = seq(size);
+                let mut s = seq(size);
 
-        //(1  2  3  4  5  6  7  8  9 10 11 12 13 14 15)
-        case(rotate_f, 15, 15);
+                unsafe { ptr_griesmills_rotate_opt(l, v.as_mut_ptr().add(l), size - l) };
+                s.rotate_left(l);
 
-        //(1  2  3  4  5  6  7  8  9 10 11 12 13 14 15)
-        case(rotate_f, 100_000, 0);
+                assert_eq!(v, s, "size = {size}, left = {l}");
+            }
+        }
     }
 
     #[test]
-    fn ptr_griesmills_rotate_rec_correct() {
-        test_correct(ptr_griesmills_rotate_rec::<usize>);
+    fn ptr_grail_rotate_correct() {
+        test_correct(ptr_grail_rotate::<usize>);
     }
 
     #[test]
-    fn ptr_griesmills_rotate_correct() {
-        test_correct(ptr_griesmills_rotate::<usize>);
+    fn ptr_swap_rotate_correct() {
+        test_correct(ptr_swap_rotate::<usize>);
+
+        // nothing is duplicated or dropped by the swaps
+        sweep_counted(|l, p, r| unsafe { ptr_swap_rotate(l, p, r) });
     }
 
     #[test]
     fn ptr_drill_rotate_correct() {
         test_correct(ptr_drill_rotate::<usize>);
     }
+
+    #[test]
+    fn ptr_drill_rotate_thresh_correct() {
+        test_correct(|l, p, r| unsafe { ptr_drill_rotate_thresh::<usize>(l, p, r, 1) });
+        test_correct(|l, p, r| unsafe { ptr_drill_rotate_thresh::<usize>(l, p, r, 2) });
+        test_correct(|l, p, r| unsafe { ptr_drill_rotate_thresh::<usize>(l, p, r, 3) });
+        test_correct(|l, p, r| unsafe { ptr_drill_rotate_thresh::<usize>(l, p, r, 4) });
+    }
 }
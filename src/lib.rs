@@ -24,15 +24,18 @@ SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SO↓FTWARE.
 */
 
 #![doc = include_str!("../README.md")]
-//#![feature(sized_type_properties)]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "nightly", feature(sized_type_properties))]
+#![cfg_attr(feature = "simd", feature(portable_simd, min_specialization))]
 
-use std::mem::MaybeUninit;
-//use std::mem::SizedTypeProperties;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
-use std::cmp;
+use core::mem::MaybeUninit;
 
-use std::ptr;
-use std::slice;
+use core::cmp;
+
+use core::ptr;
 
 pub mod buf;
 pub use buf::*;
@@ -43,6 +46,50 @@ pub use utils::*;
 pub mod gm;
 pub use gm::*;
 
+pub mod slice;
+pub use slice::*;
+
+pub mod gcd;
+pub use gcd::*;
+
+pub mod ring;
+pub use ring::*;
+
+#[cfg(feature = "alloc")]
+pub mod stream;
+#[cfg(feature = "alloc")]
+pub use stream::*;
+
+#[cfg(feature = "stats")]
+pub mod stats;
+#[cfg(feature = "stats")]
+pub use stats::*;
+
+#[cfg(feature = "trace")]
+pub mod trace;
+#[cfg(feature = "trace")]
+pub use trace::*;
+
+#[cfg(feature = "parallel")]
+pub mod parallel;
+#[cfg(feature = "parallel")]
+pub use parallel::*;
+
+#[cfg(feature = "ndarray")]
+pub mod ndarray_support;
+#[cfg(feature = "ndarray")]
+pub use ndarray_support::*;
+
+#[cfg(feature = "bench")]
+#[doc(hidden)]
+pub mod bench_support;
+
+#[cfg(test)]
+mod proptests;
+
+#[cfg(test)]
+mod test_utils;
+
 /// # Edge case (optimal for left && right <= 2)
 ///
 /// Rotates the range `[mid-left, mid+right)` such that the element
@@ -51,11 +98,18 @@ pub use gm::*;
 ///
 /// This is the fastest way to calculate `left <= 2` and `right <= 2` edge cases.
 ///
+/// The other side is shifted with `core::ptr::copy` (`memmove`) if it spans at least
+/// [`EDGE_MEMMOVE_BYTES`] bytes, and with [`shift_left`] or [`shift_right`] otherwise.
+///
 /// ## Safety
 ///
 /// The specified range must be valid for reading and writing.
 #[inline(always)]
 pub unsafe fn ptr_edge_rotate<T>(left: usize, mid: *mut T, right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
     if left == 0 || right == 0 {
         return;
     }
@@ -66,28 +120,28 @@ pub unsafe fn ptr_edge_rotate<T>(left: usize, mid: *mut T, right: usize) {
     if left == 1 && right == 1 {
         ptr::swap(start, mid);
     } else if left == right {
-        ptr::swap_nonoverlapping(start, mid, right);
+        swap_halves(start, mid, right);
     } else if left == 1 {
         let tmp = start.read();
 
-        shift_left(1, mid, right);
+        edge_shift(mid, right, -1);
         end.write(tmp);
     } else if left == 2 {
         let (a, b) = (start.read(), start.add(1).read());
 
-        shift_left(left, mid, right);
+        edge_shift(mid, right, -2);
 
         end.sub(1).write(a);
         end.write(b);
     } else if right == 1 {
         let tmp = mid.read();
 
-        shift_right(left, mid, right);
+        edge_shift(start, left, 1);
         start.write(tmp);
     } else if right == 2 {
         let (a, b) = (mid.read(), mid.add(1).read());
 
-        shift_right(left, mid, right);
+        edge_shift(start, left, 2);
 
         start.write(a);
         start.add(1).write(b);
@@ -97,6 +151,78 @@ pub unsafe fn ptr_edge_rotate<T>(left: usize, mid: *mut T, right: usize) {
     }
 }
 
+/// Shifts `[src, src + count)` by `offset` for [`ptr_edge_rotate`]: with `core::ptr::copy` if it
+/// spans at least [`EDGE_MEMMOVE_BYTES`] bytes, with [`shift_by`] otherwise.
+#[inline(always)]
+unsafe fn edge_shift<T>(src: *mut T, count: usize, offset: isize) {
+    if count * core::mem::size_of::<T>() >= EDGE_MEMMOVE_BYTES {
+        ptr::copy(src, src.offset(offset), count);
+    } else {
+        shift_by(src, count, offset);
+    }
+}
+
+/// # Tiny rotation (optimal for left + right <= 8)
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// For `left + right <= 8` a `match` on the length selects a version with a compile-time size
+/// `N`: the whole range is loaded at once as a `[T; N]`, then element `i` is stored from
+/// `(i + left) % N`. The store loop is unrolled, so apart from the match there are no branches and
+/// no `gcd`. Longer ranges are rotated with [`stable_ptr_rotate`].
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+///
+/// ## Example
+///
+/// ```text
+///          mid
+/// left = 3 |  right = 4
+/// [ 1  2  3* 4  5  6  7]  // load: tmp = [1  2  3  4  5  6  7]
+/// [ 4  5  6  7: 1  2  3]  // store: tmp[(i + 3) % 7]
+/// ```
+pub unsafe fn ptr_tiny_rotate<T>(left: usize, mid: *mut T, right: usize) {
+    #[inline(always)]
+    unsafe fn tiny<T, const N: usize>(left: usize, start: *mut T) {
+        let tmp = start.cast::<MaybeUninit<[T; N]>>().read();
+        let tmp = tmp.as_ptr().cast::<T>();
+
+        // `N` is a constant, so the loop is unrolled and the index is a select, not a branch
+        for i in 0..N {
+            let j = if i + left < N { i + left } else { i + left - N };
+            start.add(i).write(tmp.add(j).read());
+        }
+    }
+
+    if is_zst::<T>() {
+        return;
+    }
+
+    if left == 0 || right == 0 {
+        return;
+    }
+
+    let start = mid.sub(left);
+
+    // both sides are non-empty, so `left + right >= 2`
+    match left + right {
+        2 => tiny::<T, 2>(left, start),
+        3 => tiny::<T, 3>(left, start),
+        4 => tiny::<T, 4>(left, start),
+        5 => tiny::<T, 5>(left, start),
+        6 => tiny::<T, 6>(left, start),
+        7 => tiny::<T, 7>(left, start),
+        8 => tiny::<T, 8>(left, start),
+        _ => stable_ptr_rotate(left, mid, right),
+    }
+}
+
 /// # ContrevB (Generalized conjoined triple reversal) rotation
 ///
 /// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
@@ -194,6 +320,10 @@ pub unsafe fn ptr_edge_rotate<T>(left: usize, mid: *mut T, right: usize) {
 /// [ a ~~~~~~~~~ e  f  g: 1* 2  3  4 ~~~~~~~~~ 8]
 /// ```
 pub unsafe fn ptr_block_contrev_rotate<T>(left: usize, mid: *mut T, right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
     if left <= 2 || right <= 2 || left == right {
         ptr_edge_rotate(left, mid, right);
         return;
@@ -210,6 +340,9 @@ pub unsafe fn ptr_block_contrev_rotate<T>(left: usize, mid: *mut T, right: usize
         let half_min = cmp::min(left, right) / block_size / 2;
         let half_max = cmp::max(left, right) / block_size / 2;
 
+        // If the smaller side is a single block, `half_min == 0`: there are no four-cycles, and
+        // the three-cycles and the center reversal move the smaller side on their own.
+
         for _ in 0..half_min {
             // Permutation (ls, le, re, rs)
             for _ in 0..block_size {
@@ -254,7 +387,8 @@ pub unsafe fn ptr_block_contrev_rotate<T>(left: usize, mid: *mut T, right: usize
             }
         }
 
-        let center = (re.offset_from(ls).abs() / 2) as usize / block_size;
+        // `[ls, re + block_size)` holds the blocks left to reverse
+        let center = (re.offset_from(ls) / block_size as isize + 1) as usize / 2;
 
         for _ in 0..center {
             for _ in 0..block_size {
@@ -272,7 +406,7 @@ pub unsafe fn ptr_block_contrev_rotate<T>(left: usize, mid: *mut T, right: usize
 
 // unsafe fn print<T>(label: &str, mut p: *const T, size: usize)
 // where
-//     T: std::fmt::Debug,
+//     T: core::fmt::Debug,
 // {
 //     print!("{} [", label);
 
@@ -305,6 +439,10 @@ pub unsafe fn ptr_block_contrev_rotate<T>(left: usize, mid: *mut T, right: usize
 /// array. Upon completion the left and right block will be swapped. There's
 /// no known first publication, but it was prior to 1981." <<https://github.com/scandum/rotate>>
 ///
+/// If `left <= 2`, `right <= 2` or `left == right` [`ptr_edge_rotate`] is used instead: it moves
+/// every element once, while the reversals move the whole range twice. The `RevEdge` benchmark
+/// group compares the two, against the three reversals of [`ptr_reversal_rotate_progress`].
+///
 /// ## Safety
 ///
 /// The specified range must be valid for reading and writing.
@@ -323,6 +461,10 @@ pub unsafe fn ptr_block_contrev_rotate<T>(left: usize, mid: *mut T, right: usize
 /// [10 11 12 13 14 15 :1  2  3* 4  5  6  7  8  9]
 /// ```
 pub unsafe fn ptr_reversal_rotate<T>(left: usize, mid: *mut T, right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
     if right <= 2 || left <= 2 || left == right {
         ptr_edge_rotate(left, mid, right);
         return;
@@ -330,37 +472,93 @@ pub unsafe fn ptr_reversal_rotate<T>(left: usize, mid: *mut T, right: usize) {
 
     let start = mid.sub(left);
 
-    #[inline(always)]
-    unsafe fn reverse_slice<T>(p: *mut T, size: usize) {
-        if size <= 3 {
-            ptr::swap(p, p.add(size).sub(1));
-        } else {
-            let slice = slice::from_raw_parts_mut(p, size);
-            slice.reverse();
-        }
-    }
-
     reverse_slice(start, left);
     reverse_slice(mid, right);
     reverse_slice(start, left + right);
 }
 
-/// # Triple block reversal rotation
+/// # Triple reversal rotation (Right)
 ///
-/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
-/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
-/// right.
+/// Rotates the range `[end - rest - count, end)` right by `count` elements: the last `count`
+/// elements move to the front, the first `rest` elements move to the end.
+///
+/// Same as [`ptr_reversal_rotate`]`(rest, end.sub(count), count)`, but in "rotate right" terms.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+///
+/// ## Example
+///
+/// ```text
+///                    count = 4
+/// [ 1  2  3  4  5* 6  7  8  9]  // rotate right
+/// [ 6  7  8  9: 1  2  3  4  5]
+/// ```
+pub unsafe fn ptr_reversal_rotate_right<T>(count: usize, end: *mut T, rest: usize) {
+    ptr_reversal_rotate(rest, end.sub(count), count);
+}
+
+/// # Triple reversal rotation (with progress)
+///
+/// Same as [`ptr_reversal_rotate`], but calls `on_progress` after each of the three reversals
+/// with the number of elements touched so far: `left`, `left + right` and finally
+/// `2 * (left + right)`. Useful to report the progress of very large rotations.
+///
+/// Unlike [`ptr_reversal_rotate`], small cases are not delegated to [`ptr_edge_rotate`], so the
+/// callback is always called exactly three times.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing. `on_progress` must not access
+/// the range: it is called while the rotation is in progress.
+///
+/// ## Example
+///
+/// ```text
+///                mid
+///      left = 5  |  right = 4
+/// [ 1  2  3  4  5* 6  7  8  9]  // reverse left, on_progress(5)
+/// [ 5  4  3  2  1  6  7  8  9]  // reverse right, on_progress(9)
+/// [ 5  4  3  2  1  9  8  7  6]  // reverse all, on_progress(18)
+/// [ 6  7  8  9: 1  2  3  4  5]
+/// ```
+pub unsafe fn ptr_reversal_rotate_progress<T>(
+    left: usize,
+    mid: *mut T,
+    right: usize,
+    on_progress: &mut dyn FnMut(usize),
+) {
+    let start = mid.sub(left);
+
+    core::slice::from_raw_parts_mut(start, left).reverse();
+    on_progress(left);
+
+    core::slice::from_raw_parts_mut(mid, right).reverse();
+    on_progress(left + right);
+
+    core::slice::from_raw_parts_mut(start, left + right).reverse();
+    on_progress(2 * (left + right));
+}
+
+/// # Reverse and rotate
+///
+/// Reverses the range `[mid-left, mid+right)` and then rotates it `left` elements to the left,
+/// in one pass. The element at `mid + right - 1 - i` ends up at `mid - left + (i + right) %
+/// (left + right)`, i.e. the result starts with the element at index `left` of the reversed
+/// range.
 ///
 /// ## Algorithm
 ///
-/// 0. Block size = GCD(left, right);
-/// 1. Reverse blocks of l-side;
-/// 2. reverse blocks of r-side;
-/// 3. revere all blocks.
+/// Let the range be `P ++ Q` with `|P| = right` and `|Q| = left`. Reversing it gives `rev(Q) ++
+/// rev(P)`, and rotating that by `|rev(Q)| = left` gives `rev(P) ++ rev(Q)`: the last reversal of
+/// the rotation cancels the first one, and what remains is
 ///
-/// "This is an easy and reliable way to rotate in-place. You reverse the
-/// left side, next you reverse the right side, next you reverse the entire
-/// array. Upon completion the left and right block will be swapped."
+/// 1. reverse the first `right` elements;
+/// 2. reverse the last `left` elements.
+///
+/// Every element is moved at most once, against twice for a reversal followed by a
+/// [`ptr_reversal_rotate`] (or once plus a rotation for other algorithms).
 ///
 /// ## Safety
 ///
@@ -369,48 +567,26 @@ pub unsafe fn ptr_reversal_rotate<T>(left: usize, mid: *mut T, right: usize) {
 /// ## Example
 ///
 /// ```text
-///                            mid
-///        left = 9            |    right = 6
-/// [ 1  2  3  4  5  6 :7  8  9* a  b  c  d  e  f]  // reverse left side blocks
-///   ↓        ↓        ↓
-/// [ 7  .  9  4  .  6  1 ~~~ 3  a  .  c  d  .  f]  // reverse right side blocks
-///                              ↓        ↓
-/// [ 7  .  9  4  .  6  1 ~~~ 3  d  .  f  a  .  c]  // reverse all blocks
-///   ↓        ↓                 ↓        ↓
-/// [ a ~~~ c  d ~~~ f  1 ~~~ 3  4 ~~~ 6  7 ~~~ 9]
+///                mid
+///      left = 5  |  right = 4
+/// [ 1  2  3  4  5* 6  7  8  9]  // reverse first right
+///   ↓  ↓  ↓  ↓
+/// [ 4  3  2  1  5  6  7  8  9]  // reverse last left
+///               ↓  ↓  ↓  ↓  ↓
+/// [ 4  3  2  1  9  8  7  6  5]
 /// ```
-pub unsafe fn ptr_block_reversal_rotate<T>(left: usize, mid: *mut T, right: usize) {
-    if right <= 2 || left <= 2 || left == right {
-        ptr_edge_rotate(left, mid, right);
+pub unsafe fn ptr_reverse_rotate<T>(left: usize, mid: *mut T, right: usize) {
+    if is_zst::<T>() {
         return;
     }
 
     let start = mid.sub(left);
 
-    let block_size = gcd::binary_usize(left, right);
-
-    if block_size == 1 {
-        ptr_reversal_rotate(left, mid, right);
-    } else {
-        #[inline(always)]
-        unsafe fn reverse<T>(p: *mut T, count: usize, block_size: usize) {
-            let mut start = p;
-            let mut end = p.add((count - 1) * block_size);
-
-            for _ in 0..count / 2 {
-                ptr::swap_nonoverlapping(start, end, block_size);
-                start = start.add(block_size);
-                end = end.sub(block_size);
-            }
-        }
-
-        reverse(start, left / block_size, block_size);
-        reverse(mid, right / block_size, block_size);
-        reverse(start, (left + right) / block_size, block_size);
-    }
+    core::slice::from_raw_parts_mut(start, right).reverse();
+    core::slice::from_raw_parts_mut(start.add(right), left).reverse();
 }
 
-/// # Successive aka Piston rotation (recursive variant)
+/// # Triple reversal rotation (Tiled)
 ///
 /// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
 /// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
@@ -418,18 +594,13 @@ pub unsafe fn ptr_block_reversal_rotate<T>(left: usize, mid: *mut T, right: usiz
 ///
 /// ## Algorithm
 ///
-/// 1. Swap the smallest side to its place;
-/// 2. repeat for a smaller array.
-///
-/// "First described by *Gries and Mills* in *1981*, this rotation is very similar to
-/// the Gries-Mills rotation but performs non-linear swaps. It is implemented as
-/// the *Piston Rotation* in the benchmark, named after a loop optimization that
-/// removes up to `log n` branch mispredictions by performing both a left and
-/// rightward rotation in each loop." <<https://github.com/scandum/rotate>>
-///
-/// ## Properties
+/// Same three reversals as in [`ptr_reversal_rotate`], for ranges much larger than the L2 cache.
+/// Every reversal exchanges a front tile of `tile` elements with the matching back tile, reversed.
+/// The side reversals go tile by tile from their centers outwards, so the outer tiles they touch
+/// last are still hot when the final inward reversal starts with them.
 ///
-/// * During the computation `mid` is never shifted.
+/// [`reversal_tile`] gives a tile of a quarter of the L2 cache. A `tile` of `0` is treated as
+/// `1`, and a `tile` larger than the range makes it a plain [`ptr_reversal_rotate`].
 ///
 /// ## Safety
 ///
@@ -439,44 +610,81 @@ pub unsafe fn ptr_block_reversal_rotate<T>(left: usize, mid: *mut T, right: usiz
 ///
 /// ```text
 ///                            mid
-///           left = 9         |    right = 6
-/// [ 1  2  3  4  5  6: 7  8  9*10 11 12 13 14 15]  // swap
-///   └──────────────┴─────/\────┴──────────────┘
-///   ┌──────────────┬─────~/────┬──────────────┐
-/// [10 ~~~~~~~~~~~ 15: 7  .  9  1  -  -  -  -  6]
-///
-///                      l = 3        r = 6
-///  10  .  .  .  . 15[ 7  .  9* 1  -  3: 4  -  6]  // swap
-///                     └─────┴────/\─────┴─────┘
-///                     ┌─────┬────\~─────┬─────┐
-///  10  .  .  .  . 15[ 4  -  6  1  -  3  7 ~~~ 9]
-///
-///                       l = 3   r = 3
-///  10  .  .  .  . 15[ 4  -  6; 1  -  3] 7 ~~~ 9   // swap
-///                     └─────┴/\┴─────┘
-///                     ┌─────┬~~┬─────┐
-///  10  .  .  .  . 15[ 1 ~~~ 3  4 ~~~ 6] 7 ~~~ 9
-///
-/// [10  .  .  .  . 15: 1 ~~~ 3* 4 ~~~~~~~~~~~~ 9]
+///        left = 9            |    right = 6      tile = 2
+/// [ 1  2  3  4  5  6  7  8  9*10 11 12 13 14 15]  // reverse left: 5, (3 4)-(6 7), (1 2)-(8 9)
+/// [ 9  8  7  6  5  4  3  2  1 10 11 12 13 14 15]  // reverse right: 12 13, (10 11)-(14 15)
+/// [ 9  8  7  6  5  4  3  2  1 15 14 13 12 11 10]  // reverse all: (9 8)-(11 10), ..., center
+/// [10 11 12 13 14 15: 1  2  3* 4  5  6  7  8  9]
 /// ```
-pub unsafe fn ptr_piston_rotate_rec<T>(left: usize, mid: *mut T, right: usize) {
-    if left <= 2 || right <= 2 || left == right {
+pub unsafe fn ptr_tiled_reversal_rotate<T>(left: usize, mid: *mut T, right: usize, tile: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    if right <= 2 || left <= 2 || left == right {
         ptr_edge_rotate(left, mid, right);
         return;
     }
 
+    /// Reverses `[p, p + count)` by pairs of tiles: from the center outwards or from the ends
+    /// inwards.
+    #[inline(always)]
+    unsafe fn reverse_tiled<T>(p: *mut T, count: usize, tile: usize, outwards: bool) {
+        let tiles = count / 2 / tile;
+        let center = count - 2 * tiles * tile;
+
+        #[inline(always)]
+        unsafe fn exchange<T>(p: *mut T, count: usize, tile: usize, k: usize) {
+            let front = p.add(k * tile);
+            let back = p.add(count - k * tile - 1);
+
+            for i in 0..tile {
+                ptr::swap_nonoverlapping(front.add(i), back.sub(i), 1);
+            }
+        }
+
+        let reverse_center = || {
+            core::slice::from_raw_parts_mut(p.add(tiles * tile), center).reverse();
+        };
+
+        if outwards {
+            reverse_center();
+
+            for k in (0..tiles).rev() {
+                exchange(p, count, tile, k);
+            }
+        } else {
+            for k in 0..tiles {
+                exchange(p, count, tile, k);
+            }
+
+            reverse_center();
+        }
+    }
+
     let start = mid.sub(left);
+    let tile = cmp::max(tile, 1);
 
-    if left < right {
-        ptr::swap_nonoverlapping(start, start.add(right), left);
-        ptr_piston_rotate_rec(left, mid, right - left);
+    reverse_tiled(start, left, tile, true);
+    reverse_tiled(mid, right, tile, true);
+    reverse_tiled(start, left + right, tile, false);
+}
+
+/// Tile size (in elements) for [`ptr_tiled_reversal_rotate`]: a quarter of [`L2_CACHE_BYTES`].
+///
+/// Every step exchanges a front tile with a back tile, so the pair takes half of the L2 cache,
+/// leaving the other half for the rest of the working set (the stack, the next pair, other code).
+pub const fn reversal_tile<T>() -> usize {
+    let size = core::mem::size_of::<T>();
+
+    if size == 0 || size >= L2_CACHE_BYTES / 4 {
+        1
     } else {
-        ptr::swap_nonoverlapping(mid, start, right);
-        ptr_piston_rotate_rec(left - right, mid, right);
+        L2_CACHE_BYTES / 4 / size
     }
 }
 
-/// # Successive aka Piston rotation
+/// # Triple reversal rotation (recursive variant)
 ///
 /// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
 /// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
@@ -484,74 +692,77 @@ pub unsafe fn ptr_piston_rotate_rec<T>(left: usize, mid: *mut T, right: usize) {
 ///
 /// ## Algorithm
 ///
-/// 1. Swap the smallest side to its place;
-/// 2. repeat for a smaller array.
-///
-/// "First described by *Gries and Mills* in *1981*, this rotation is very similar to
-/// the *Gries-Mills rotation* but performs non-linear swaps. It is implemented as
-/// the *Piston rotation* in the benchmark, named after a loop optimization that
-/// removes up to `log n` branch mispredictions by performing both a left and
-/// rightward rotation in each loop." <<https://github.com/scandum/rotate>>
-///
-/// ## Properties
+/// 1. Reverse the smallest side;
+/// 2. reverse the equal-length part of the largest side next to it;
+/// 3. reverse them both, so the smallest side is swapped to its place;
+/// 4. repeat for a smaller array.
 ///
-/// * During computation `mid` is never shifted.
+/// It is the *Piston rotation* with swaps made of triple reversals. Every step is a tail call,
+/// so the recursion is a loop and the stack depth does not grow with `max / min`.
 ///
 /// ## Safety
 ///
 /// The specified range must be valid for reading and writing.
 ///
-/// # Example
+/// ## Example
 ///
 /// ```text
 ///                            mid
-///           left = 9         |    right = 6
-/// [ 1  2  3  4  5  6: 7  8  9*10 11 12 13 14 15]  // swap
-///   └──────────────┴─────/\────┴──────────────┘
-///   ┌──────────────┬─────~/────┬──────────────┐
-/// [10 ~~~~~~~~~~~ 15: 7  .  9  1  -  -  -  -  6]
-///
-///                       l = 3        r = 6
-///  10  .  .  .  . 15[ 7  .  9* 1  -  3: 4  -  6]  // swap
-///                     └─────┴─────/\────┴─────┘
-///                     ┌─────┬─────\~────┬─────┐
-///  10  .  .  .  . 15[ 4  -  6  1  -  3  7 ~~~ 9]
-///
-///                       l = 3   r = 3
-///  10  .  .  .  . 15[ 4  -  6; 1  -  3] 7  .  9   // swap
-///                     └─────┴/\┴─────┘
-///                     ┌─────┬~~┬─────┐
-///  10  .  .  .  . 15[ 1 ~~~ 3  4 ~~~ 6] 7  .  9
-///
-/// [10  .  .  .  . 15: 1  .  3* 4  .  .  .  .  9]
+///        left = 9            |    right = 6
+/// [ 1  2  3  4  5  6: 7  8  9*10 11 12 13 14 15]  // reverse x3
+///            ↓  ↓  ↓  ↓  ↓  ↓  ↓  ↓  ↓  ↓  ↓  ↓
+/// [ 1  2  3 10 11 12 13 14 15  4  5  6  7  8  9]
+///
+///      l = 3   r = 6
+/// [ 1  2  3*10 11 12 13 14 15] 4 ~~~~~~~~~~~~ 9   // reverse x3
+///   ↓  ↓  ↓  ↓  ↓  ↓
+/// [10 11 12  1  2  3 13 14 15] 4 ~~~~~~~~~~~~ 9
+///
+///               l = 3   r = 3
+///  10 ~~ 12[ 1  2  3*13 14 15] 4 ~~~~~~~~~~~~ 9   // swap
+///            └─────┴/\┴─────┘
+///            ┌─────┬~~┬─────┐
+///  10 ~~ 12[13 14 15  1  2  3] 4 ~~~~~~~~~~~~ 9
+///
+/// [10  .  .  .  . 15: 1  2  3* 4  .  .  .  .  9]
 /// ```
-pub unsafe fn ptr_piston_rotate<T>(mut left: usize, mid: *mut T, mut right: usize) {
+pub unsafe fn ptr_reversal_rotate_rec<T>(mut left: usize, mut mid: *mut T, mut right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
     loop {
-        if left <= 2 {
-            break;
+        if left <= 2 || right <= 2 {
+            ptr_edge_rotate(left, mid, right);
+            return;
         }
 
-        while left <= right {
-            ptr::swap_nonoverlapping(mid.sub(left), mid.add(right - left), left);
-            right -= left;
+        if left == right {
+            swap_halves(mid.sub(left), mid, right);
+            return;
         }
 
-        if right <= 2 {
-            break;
-        }
+        let start = mid.sub(left);
 
-        while left >= right {
-            ptr::swap_nonoverlapping(mid, mid.sub(left), right);
+        if left < right {
+            reverse_slice(start, left);
+            reverse_slice(mid, left);
+            reverse_slice(start, 2 * left);
+
+            mid = mid.add(left);
+            right -= left;
+        } else {
+            reverse_slice(mid.sub(right), right);
+            reverse_slice(mid, right);
+            reverse_slice(mid.sub(right), 2 * right);
+
+            mid = mid.sub(right);
             left -= right;
         }
     }
-
-    if left <= 2 || right <= 2 {
-        ptr_edge_rotate(left, mid, right);
-    }
 }
 
-/// # Helix rotation
+/// # Triple block reversal rotation
 ///
 /// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
 /// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
@@ -559,9 +770,477 @@ pub unsafe fn ptr_piston_rotate<T>(mut left: usize, mid: *mut T, mut right: usiz
 ///
 /// ## Algorithm
 ///
-/// "The helix rotation has similarities with the *Gries-Mills
-/// rotation* but has a distinct sequential movement pattern. It is
-/// an improvement upon the *Grail rotation* by merging the two inner
+/// 0. Block size = GCD(left, right);
+/// 1. Reverse blocks of l-side;
+/// 2. reverse blocks of r-side;
+/// 3. revere all blocks.
+///
+/// "This is an easy and reliable way to rotate in-place. You reverse the
+/// left side, next you reverse the right side, next you reverse the entire
+/// array. Upon completion the left and right block will be swapped."
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+///
+/// ## Example
+///
+/// ```text
+///                            mid
+///        left = 9            |    right = 6
+/// [ 1  2  3  4  5  6 :7  8  9* a  b  c  d  e  f]  // reverse left side blocks
+///   ↓        ↓        ↓
+/// [ 7  .  9  4  .  6  1 ~~~ 3  a  .  c  d  .  f]  // reverse right side blocks
+///                              ↓        ↓
+/// [ 7  .  9  4  .  6  1 ~~~ 3  d  .  f  a  .  c]  // reverse all blocks
+///   ↓        ↓                 ↓        ↓
+/// [ a ~~~ c  d ~~~ f  1 ~~~ 3  4 ~~~ 6  7 ~~~ 9]
+/// ```
+pub unsafe fn ptr_block_reversal_rotate<T>(left: usize, mid: *mut T, right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    if right <= 2 || left <= 2 || left == right {
+        ptr_edge_rotate(left, mid, right);
+        return;
+    }
+
+    let start = mid.sub(left);
+
+    let block_size = gcd::binary_usize(left, right);
+
+    if block_size == 1 {
+        ptr_reversal_rotate(left, mid, right);
+    } else {
+        reverse_blocks(start, left / block_size, block_size);
+        reverse_blocks(mid, right / block_size, block_size);
+        reverse_blocks(start, (left + right) / block_size, block_size);
+    }
+}
+
+/// # Triple block reversal rotation (Type-erased)
+///
+/// Rotates `left_elems + right_elems` elements of `elem_size` bytes each, starting at `ptr`, such
+/// that the element at index `left_elems` becomes the first one. For FFI and serialization code
+/// that only knows the element size at runtime.
+///
+/// ## Algorithm
+///
+/// [`ptr_block_reversal_rotate`] over `u8` with a runtime block size: every element is an opaque
+/// block of `elem_size` bytes, and the blocks of `gcd(left_elems, right_elems)` elements are
+/// reversed with [`reverse_blocks`] -- the l-side, the r-side, then all of them.
+///
+/// ## Safety
+///
+/// The region `[ptr, ptr + (left_elems + right_elems) * elem_size)` must be valid for reading and
+/// writing. Elements are moved as bytes, so `ptr` needs no alignment (`u8` has align `1`), and
+/// they must be fine to move bitwise, like any Rust value.
+///
+/// ## Example
+///
+/// ```text
+///                 left_elems = 3, right_elems = 2, elem_size = 2
+/// [ a1 a2  b1 b2  c1 c2* d1 d2  e1 e2]  // rotate_bytes_raw
+/// [ d1 d2  e1 e2: a1 a2  b1 b2  c1 c2]
+/// ```
+pub unsafe fn rotate_bytes_raw(
+    ptr: *mut u8,
+    elem_size: usize,
+    left_elems: usize,
+    right_elems: usize,
+) {
+    if elem_size == 0 || left_elems == 0 || right_elems == 0 {
+        return;
+    }
+
+    let gcd = gcd::binary_usize(left_elems, right_elems);
+    let block_size = gcd * elem_size;
+
+    let (left, right) = (left_elems / gcd, right_elems / gcd);
+
+    reverse_blocks(ptr, left, block_size);
+    reverse_blocks(ptr.add(left * block_size), right, block_size);
+    reverse_blocks(ptr, left + right, block_size);
+}
+
+/// # Triple block reversal rotation (Const block)
+///
+/// Rotates the range `[mid - left_blocks * B, mid + right_blocks * B)` such that the element at
+/// `mid` becomes the first element. Note that `left_blocks` and `right_blocks` are counted in
+/// blocks of `B` elements, not in elements.
+///
+/// ## Algorithm
+///
+/// Same as [`ptr_block_reversal_rotate`] with `block_size == B` known at compile time: the range
+/// is rotated by [`ptr_reversal_rotate`] as a range of `[T; B]`, so every block is moved with a
+/// single `[T; B]` copy that LLVM can fully unroll. Handy for e.g. RGBA pixels (`B = 4`).
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+///
+/// ## Example
+///
+/// ```text
+///                   mid
+///   left_blocks = 2 |  right_blocks = 3, B = 2
+/// [ 1  2  3  4* a  b  c  d  e  f]
+/// [ a ~~~~~~~~~~~~ f: 1  2  3  4]
+/// ```
+pub unsafe fn ptr_const_block_reversal_rotate<const B: usize, T>(
+    left_blocks: usize,
+    mid: *mut T,
+    right_blocks: usize,
+) {
+    // `[T; B]` has the alignment of `T`, so `mid` is a valid `*mut [T; B]`.
+    ptr_reversal_rotate::<[T; B]>(left_blocks, mid.cast::<[T; B]>(), right_blocks);
+}
+
+/// # Rotate rows
+///
+/// Rotates the rows of the row-major `rows x cols` matrix at `data` such that the row `k % rows`
+/// becomes the first one, i.e. shifts the rows cyclically up by `k`. Every row is moved as a
+/// block of `cols` elements with [`reverse_blocks`], like [`ptr_block_reversal_rotate`] does.
+///
+/// An empty matrix (`rows == 0` or `cols == 0`) is left untouched.
+///
+/// ## Safety
+///
+/// The region `[data, data + rows * cols)` must be valid for reading and writing.
+///
+/// ## Example
+///
+/// ```text
+///   rows = 5, cols = 3, k = 2
+/// [ a  a  a]      [ c  c  c]
+/// [ b  b  b]      [ d  d  d]
+/// [ c  c  c]  ->  [ e  e  e]
+/// [ d  d  d]      [ a  a  a]
+/// [ e  e  e]      [ b  b  b]
+/// ```
+pub unsafe fn rotate_rows<T>(data: *mut T, rows: usize, cols: usize, k: usize) {
+    if is_zst::<T>() || rows == 0 || cols == 0 {
+        return;
+    }
+
+    let k = k % rows;
+
+    if k == 0 {
+        return;
+    }
+
+    reverse_blocks(data, k, cols);
+    reverse_blocks(data.add(k * cols), rows - k, cols);
+    reverse_blocks(data, rows, cols);
+}
+
+/// # Rotate columns
+///
+/// Rotates the columns of the row-major `rows x cols` matrix at `data` such that the column
+/// `k % cols` becomes the first one, i.e. shifts the columns cyclically left by `k`. Columns are
+/// strided, so instead of moving them as blocks every row is rotated left by `k` with
+/// [`stable_ptr_rotate`], which is the same permutation.
+///
+/// An empty matrix (`rows == 0` or `cols == 0`) is left untouched.
+///
+/// ## Safety
+///
+/// The region `[data, data + rows * cols)` must be valid for reading and writing.
+///
+/// ## Example
+///
+/// ```text
+///   rows = 3, cols = 5, k = 2
+/// [ a  b  c  d  e]      [ c  d  e  a  b]
+/// [ a  b  c  d  e]  ->  [ c  d  e  a  b]
+/// [ a  b  c  d  e]      [ c  d  e  a  b]
+/// ```
+pub unsafe fn rotate_cols<T>(data: *mut T, rows: usize, cols: usize, k: usize) {
+    if is_zst::<T>() || rows == 0 || cols == 0 {
+        return;
+    }
+
+    let k = k % cols;
+
+    if k == 0 {
+        return;
+    }
+
+    for row in 0..rows {
+        stable_ptr_rotate(k, data.add(row * cols + k), cols - k);
+    }
+}
+
+/// # Comb rotation
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// Triple reversal rotation where every reversal is done comb-wise: instead of swapping single
+/// elements from both ends, it first swaps the two outermost blocks of `count / 2` elements with
+/// `ptr::swap_nonoverlapping`, then does the same inside each of the blocks.
+///
+/// Gap schedule: the block (gap) size starts at the half of the reversed range and halves
+/// every pass; once a block has `<= 16` elements it is reversed element by element.
+///
+/// Every pass moves each element once, so it makes `log(n / 16)` times more moves than
+/// *reversal rotation*, but all the moves but the last ones are large vectorizable block swaps.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+///
+/// ## Example
+///
+/// Reversal of the whole array (`16` threshold is ignored here):
+///
+/// ```text
+///                            mid
+///        left = 9            |    right = 6
+/// [ 1  2  3  4  5  6 :7  8  9*10 11 12 13 14 15]  // reverse left, reverse right
+/// [ 9  8  7  6  5  4  3  2  1 15 14 13 12 11 10]  // swap blocks of 7
+///   └─────────────────┴──/\─┴─────────────────┘
+///   ┌─────────────────┬──~~─┬─────────────────┐
+/// [ 1 15 14 13 12 11 10  2  9  8  7  6  5  4  3]  // swap blocks of 3
+///   └─────┴──/\─┴─────┘     └─────┴──/\─┴─────┘
+///   ┌─────┬──~~─┬─────┐     ┌─────┬──~~─┬─────┐
+/// [12 11 10 13  1 15 14  2  5  4  3  6  9  8  7]  // swap blocks of 1
+/// [10 11 12 13 14 15 :1  2  3* 4  5  6  7  8  9]
+/// ```
+pub unsafe fn ptr_comb_rotate<T>(left: usize, mid: *mut T, right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    if right <= 2 || left <= 2 {
+        ptr_edge_rotate(left, mid, right);
+        return;
+    }
+
+    let start = mid.sub(left);
+
+    if left == right {
+        swap_halves(start, mid, left);
+        return;
+    }
+
+    unsafe fn comb_reverse<T>(p: *mut T, count: usize) {
+        if count <= 16 {
+            reverse_slice(p, count);
+            return;
+        }
+
+        let gap = count / 2;
+        let end = p.add(count - gap);
+
+        ptr::swap_nonoverlapping(p, end, gap);
+
+        comb_reverse(p, gap);
+        comb_reverse(end, gap);
+    }
+
+    comb_reverse(start, left);
+    comb_reverse(mid, right);
+    comb_reverse(start, left + right);
+}
+
+/// # Successive aka Piston rotation (recursive variant)
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// 1. Swap the smallest side to its place;
+/// 2. repeat for a smaller array.
+///
+/// "First described by *Gries and Mills* in *1981*, this rotation is very similar to
+/// the Gries-Mills rotation but performs non-linear swaps. It is implemented as
+/// the *Piston Rotation* in the benchmark, named after a loop optimization that
+/// removes up to `log n` branch mispredictions by performing both a left and
+/// rightward rotation in each loop." <<https://github.com/scandum/rotate>>
+///
+/// ## Properties
+///
+/// * During the computation `mid` is never shifted.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+///
+/// ## Example
+///
+/// ```text
+///                            mid
+///           left = 9         |    right = 6
+/// [ 1  2  3  4  5  6: 7  8  9*10 11 12 13 14 15]  // swap
+///   └──────────────┴─────/\────┴──────────────┘
+///   ┌──────────────┬─────~/────┬──────────────┐
+/// [10 ~~~~~~~~~~~ 15: 7  .  9  1  -  -  -  -  6]
+///
+///                      l = 3        r = 6
+///  10  .  .  .  . 15[ 7  .  9* 1  -  3: 4  -  6]  // swap
+///                     └─────┴────/\─────┴─────┘
+///                     ┌─────┬────\~─────┬─────┐
+///  10  .  .  .  . 15[ 4  -  6  1  -  3  7 ~~~ 9]
+///
+///                       l = 3   r = 3
+///  10  .  .  .  . 15[ 4  -  6; 1  -  3] 7 ~~~ 9   // swap
+///                     └─────┴/\┴─────┘
+///                     ┌─────┬~~┬─────┐
+///  10  .  .  .  . 15[ 1 ~~~ 3  4 ~~~ 6] 7 ~~~ 9
+///
+/// [10  .  .  .  . 15: 1 ~~~ 3* 4 ~~~~~~~~~~~~ 9]
+/// ```
+pub unsafe fn ptr_piston_rotate_rec<T>(left: usize, mid: *mut T, right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    if left <= 2 || right <= 2 || left == right {
+        ptr_edge_rotate(left, mid, right);
+        return;
+    }
+
+    let start = mid.sub(left);
+
+    if left < right {
+        ptr::swap_nonoverlapping(start, start.add(right), left);
+        ptr_piston_rotate_rec(left, mid, right - left);
+    } else {
+        ptr::swap_nonoverlapping(mid, start, right);
+        ptr_piston_rotate_rec(left - right, mid, right);
+    }
+}
+
+/// # Successive aka Piston rotation
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// 1. Swap the smallest side to its place;
+/// 2. repeat for a smaller array.
+///
+/// "First described by *Gries and Mills* in *1981*, this rotation is very similar to
+/// the *Gries-Mills rotation* but performs non-linear swaps. It is implemented as
+/// the *Piston rotation* in the benchmark, named after a loop optimization that
+/// removes up to `log n` branch mispredictions by performing both a left and
+/// rightward rotation in each loop." <<https://github.com/scandum/rotate>>
+///
+/// ## Properties
+///
+/// * During computation `mid` is never shifted.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+///
+/// # Example
+///
+/// ```text
+///                            mid
+///           left = 9         |    right = 6
+/// [ 1  2  3  4  5  6: 7  8  9*10 11 12 13 14 15]  // swap
+///   └──────────────┴─────/\────┴──────────────┘
+///   ┌──────────────┬─────~/────┬──────────────┐
+/// [10 ~~~~~~~~~~~ 15: 7  .  9  1  -  -  -  -  6]
+///
+///                       l = 3        r = 6
+///  10  .  .  .  . 15[ 7  .  9* 1  -  3: 4  -  6]  // swap
+///                     └─────┴─────/\────┴─────┘
+///                     ┌─────┬─────\~────┬─────┐
+///  10  .  .  .  . 15[ 4  -  6  1  -  3  7 ~~~ 9]
+///
+///                       l = 3   r = 3
+///  10  .  .  .  . 15[ 4  -  6; 1  -  3] 7  .  9   // swap
+///                     └─────┴/\┴─────┘
+///                     ┌─────┬~~┬─────┐
+///  10  .  .  .  . 15[ 1 ~~~ 3  4 ~~~ 6] 7  .  9
+///
+/// [10  .  .  .  . 15: 1  .  3* 4  .  .  .  .  9]
+/// ```
+pub unsafe fn ptr_piston_rotate<T>(mut left: usize, mid: *mut T, mut right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    if left == 0 || right == 0 {
+        return;
+    }
+
+    if left == right {
+        swap_halves(mid.sub(left), mid, left);
+        return;
+    }
+
+    loop {
+        if left <= 2 {
+            break;
+        }
+
+        while left <= right {
+            ptr::swap_nonoverlapping(mid.sub(left), mid.add(right - left), left);
+            right -= left;
+        }
+
+        if right <= 2 {
+            break;
+        }
+
+        while left >= right {
+            ptr::swap_nonoverlapping(mid, mid.sub(left), right);
+            left -= right;
+        }
+    }
+
+    // The loop is only left through one of the two `break`s, so one of the sides is at most `2`
+    // here; the other one is arbitrary (e.g. `(5, 3)` ends with `(2, 3)`) and either may be `0`.
+    debug_assert!(left <= 2 || right <= 2);
+
+    ptr_edge_rotate(left, mid, right);
+}
+
+/// # Successive aka Piston rotation (Right)
+///
+/// Rotates the range `[end - rest - count, end)` right by `count` elements: the last `count`
+/// elements move to the front, the first `rest` elements move to the end.
+///
+/// Same as [`ptr_piston_rotate`]`(rest, end.sub(count), count)`, but in "rotate right" terms.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+///
+/// ## Example
+///
+/// ```text
+///                    count = 4
+/// [ 1  2  3  4  5* 6  7  8  9]  // rotate right
+/// [ 6  7  8  9: 1  2  3  4  5]
+/// ```
+pub unsafe fn ptr_piston_rotate_right<T>(count: usize, end: *mut T, rest: usize) {
+    ptr_piston_rotate(rest, end.sub(count), count);
+}
+
+/// # Helix rotation
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// "The helix rotation has similarities with the *Gries-Mills
+/// rotation* but has a distinct sequential movement pattern. It is
+/// an improvement upon the *Grail rotation* by merging the two inner
 /// loops into a single loop, significantly improving performance when
 /// the relative size difference between the two halves is large. In
 /// addition it doesn't stop when the smallest block no longer fits,
@@ -572,85 +1251,450 @@ pub unsafe fn ptr_piston_rotate<T>(mut left: usize, mid: *mut T, mut right: usiz
 ///
 /// ## Safety
 ///
-/// The specified range must be valid for reading and writing.
+/// The specified range must be valid for reading and writing.
+///
+/// ## Examples
+///
+/// ```text
+///                            mid
+///           left = 9         |    right = 6
+/// [ 1  2  3  4  5  6: 7  8  9* a  b  c  d  e  f]  // swap <--
+///   └─────────────────|─/\──┘                 |
+///                     └─\/────────────────────┘
+/// [ d  .  f  a  .  c  1 ~~~~~~~~~~~~~~~~~~~~~ 9]
+/// [ d  .  f:*a  .  c] 1 ~~~~~~~~~~~~~~~~~~~~~ 9   // swap equal
+///   a ~~~ c:*d ~~~ f  1 ~~~~~~~~~~~~~~~~~~~~~ 9
+///```
+///
+/// ```text
+///                         mid
+///          left = 8       |      right = 7
+/// [ 1  2  3  4  5  6  7: 8* a  b  c  d  e  f  g]  // swap <--
+///   └────────────────/\──|                    |
+///                    \/  └────────────────────┘
+/// [ g *a  -  -  -  - :f] 1 ~~~~~~~~~~~~~~~~~~ 8   // ptr_edge_rotate
+/// [ a *b ~~~~~~~~~ f :g] 1  .  .  .  .  .  .  8
+/// ```
+pub unsafe fn ptr_helix_rotate<T>(left: usize, mid: *mut T, right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    if left == 0 || right == 0 {
+        return;
+    }
+
+    let (left, mid, right) = helix_reduce(left, mid, right);
+
+    ptr_edge_rotate(left, mid, right);
+}
+
+/// Main loop of the *Helix rotation*: swaps until the smallest side has `1` or `2` elements or
+/// the sides are equal, and returns the residual `(left, mid, right)` that is left to rotate.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+pub(crate) unsafe fn helix_reduce<T>(
+    mut left: usize,
+    mut mid: *mut T,
+    mut right: usize,
+) -> (usize, *mut T, usize) {
+    let mut start = mid.sub(left);
+    let mut end = mid.add(right);
+
+    loop {
+        if left >= right {
+            if right <= 2 || left == right {
+                break;
+            }
+
+            swap_backward(start, end.sub(left), left);
+
+            end = end.sub(left);
+            left %= right;
+            mid = start.add(left);
+            right -= left;
+        } else {
+            if left <= 2 || left == right {
+                break;
+            }
+
+            swap_forward(mid, start, right);
+
+            start = start.add(right);
+            right %= left;
+            mid = end.sub(right);
+            left -= right;
+        }
+    }
+
+    (left, mid, right)
+}
+
+/// # Direct aka Juggling aka Dolphin rotation
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at
+/// `mid` becomes the first element. Equivalently, rotates the range
+/// `left` elements to the left or `right` elements to the right.
+///
+/// ## Algorithm
+///
+/// "In Rust this algorithm is used for small values of `left + right` or for large `T`. The elements
+/// are moved into their final positions one at a time starting at `mid - left` and advancing by `right`
+/// steps modulo `left + right`, such that only one temporary is needed. Eventually, we arrive back at
+/// `mid - left`. However, if `gcd(left + right, right)` is not 1, the above steps skipped over
+/// elements."
+///
+/// "Fortunately, the number of skipped over elements between finalized elements is always equal, so
+/// we can just offset our starting position and do more rounds (the total number of rounds is the
+/// `gcd(left + right, right)` value). The end result is that all elements are finalized once and
+/// only once."
+///
+/// Its first known publication was in *1966*.
+///
+/// ## Panic safety
+///
+/// While a cycle is in progress one element lives in a temporary and its slot holds a bitwise
+/// duplicate. This can't be observed: moves in Rust are plain copies that never run user code, and
+/// the loop calls no `Clone`, `Drop` or other trait method, so it can't unwind half-way and needs
+/// no drop guard. See [`rotate_drop_safe`] for the other rotations.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing. In particular, `left + right` can't
+/// exceed [`max_rotation_len`], which is checked in debug builds.
 ///
-/// ## Examples
+/// ## Example
 ///
 /// ```text
 ///                            mid
 ///           left = 9         |    right = 6
-/// [ 1  2  3  4  5  6: 7  8  9* a  b  c  d  e  f]  // swap <--
-///   └─────────────────|─/\──┘                 |
-///                     └─\/────────────────────┘
-/// [ d  .  f  a  .  c  1 ~~~~~~~~~~~~~~~~~~~~~ 9]
-/// [ d  .  f:*a  .  c] 1 ~~~~~~~~~~~~~~~~~~~~~ 9   // swap equal
-///   a ~~~ c:*d ~~~ f  1 ~~~~~~~~~~~~~~~~~~~~~ 9
-///```
+/// [ 1  2  3  4  5  6: 7  8  9*10 11 12 13 14 15]
+///   |        |        |        |        └──────────────────╮
+///   |        |        |        └──────────────────╮        ┆
+///   |        |        └─────────────────┐         ┆        ┆
+///   |        └─────────────────┐        ┆         ┆        ┆
+///   └─────────────────┐        ┆        ┆         ┆        ┆
+/// ~──────────╮        ┆        ┆        ┆         ┆        ┆
+/// ~─╮        ┆        ┆        ┆        ┆         ┆        ┆
+///   ↓        ↓        ↓        ↓        ↓         ↓        ↓
+/// [10  2  3 13  5  6  1  8  9  4 11 12  7 14 15][10  2  3 13...
+///      |        |        |        |        └──────────────────╮
+///      |        |        |        └──────────────────╮        ┆
+///      |        |        └─────────────────┐         ┆        ┆
+///      |        └─────────────────┐        ┆         ┆        ┆
+///      └─────────────────┐        ┆        ┆         ┆        ┆
+/// ~─────────────╮        ┆        ┆        ┆         ┆        ┆
+/// ~────╮        ┆        ┆        ┆        ┆         ┆        ┆
+///   _  ↓     _  ↓     _  ↓     _  ↓     _  ↓      _  ↓     _  ↓
+/// [10 11  3 13 14  6  1  2  9  4  5 12  7  8 15][10 11  3 13 14...
+///         |        |        |        |        └──────────────────╮
+///         |        |        |        └──────────────────╮        ┆
+///         |        |        └─────────────────┐         ┆        ┆
+///         |        └─────────────────┐        ┆         ┆        ┆
+///         └─────────────────┐        ┆        ┆         ┆        ┆
+/// ~────────────────╮        ┆        ┆        ┆         ┆        ┆
+/// ~───────╮        ┆        ┆        ┆        ┆         ┆        ┆
+///   _  _  ↓  _  _  ↓  _  _  ↓  _  _  ↓  _  _  ↓   _  _  ↓  _  _  ↓
+/// [10  . 12  .  . 15: .  .  3* .  .  6  .  .  9][ .  . 12  .  . 15...
+/// ```
 ///
 /// ```text
-///                         mid
-///          left = 8       |      right = 7
-/// [ 1  2  3  4  5  6  7: 8* a  b  c  d  e  f  g]  // swap <--
-///   └────────────────/\──|                    |
-///                    \/  └────────────────────┘
-/// [ g *a  -  -  -  - :f] 1 ~~~~~~~~~~~~~~~~~~ 8   // ptr_edge_rotate
-/// [ a *b ~~~~~~~~~ f :g] 1  .  .  .  .  .  .  8
+///                            mid
+///           left = 9         |    right = 6
+/// [ 1  2  3  4  5  6: 7  8  9* a  b  c  d  e  f]
+///   └─────|  └─────|  └─────|  └─────|  └─────|
+///  ~╮     └───────────╮     |        └────────────╮
+///   |              |  |     └───────────╮     |   |
+///  ~| ───────╮     └───────────╮        |     └───| ───────╮
+///   ↓        ↓        ↓        ↓        ↓         ↓        ↓
+/// [ a ~~~ c  d ~~~ f  1 ~~~ 3  4 ~~~ 6  7 ~~~ 9][ a ~~~ c  d ~~~ f...
 /// ```
-pub unsafe fn ptr_helix_rotate<T>(mut left: usize, mut mid: *mut T, mut right: usize) {
-    let mut start = mid.sub(left);
-    let mut end = mid.add(right);
+pub unsafe fn ptr_direct_rotate<T>(left: usize, mid: *mut T, right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    debug_check_len::<T>(left, right, "ptr_direct_rotate");
+
+    // N.B. the below algorithms can fail if these cases are not checked
+    if right <= 2 || left <= 2 {
+        ptr_edge_rotate(left, mid, right);
+        return;
+    }
 
+    let start = mid.sub(left);
+
+    if left == right {
+        swap_halves(start, mid, left);
+        return;
+    }
+
+    // beginning of first round
+    let mut tmp: T = start.read();
+    let mut i = right;
+
+    // `gcd` can be found before hand by calculating `gcd(left + right, right)`,
+    // but it is faster to do one loop which calculates the gcd as a side effect, then
+    // doing the rest of the chunk
+    let mut gcd = right;
+
+    // benchmarks reveal that it is faster to swap temporaries all the way through instead
+    // of reading one temporary once, copying backwards, and then writing that temporary at
+    // the very end. This is possibly due to the fact that swapping or replacing temporaries
+    // uses only one memory address in the loop instead of needing to manage two.
     loop {
-        if left >= right {
-            if right <= 2 || left == right {
+        ptr::swap(&mut tmp, start.add(i));
+        // tmp = start.add(i).replace(tmp);
+
+        // instead of incrementing `i` and then checking if it is outside the bounds, we
+        // check if `i` will go outside the bounds on the next increment. This prevents
+        // any wrapping of pointers or `usize`.
+        if i >= left {
+            i -= left;
+            if i == 0 {
+                // end of first round
+                start.write(tmp);
                 break;
             }
+            // this conditional must be here if `left + right >= 15`
+            if i < gcd {
+                gcd = i;
+            }
+        } else {
+            i += right;
+        }
+    }
+
+    // finish the chunk with more rounds
+    for s in 1..gcd {
+        tmp = start.add(s).read();
+        i = s + right;
+
+        loop {
+            ptr::swap(&mut tmp, start.add(i));
+            // tmp = start.add(i).replace(tmp);
+            if i >= left {
+                i -= left;
+                if i == s {
+                    start.add(s).write(tmp);
+                    break;
+                }
+            } else {
+                i += right;
+            }
+        }
+    }
+}
+
+/// # Direct rotation (Chunked)
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// Same cycles as in [`ptr_direct_rotate`], but advanced in lockstep: the cycle starting at `s`
+/// visits exactly the positions of the cycle starting at `0` shifted by `s`, so `8` neighbouring
+/// cycles can be moved together with `ptr::swap_nonoverlapping` of `8` elements, which the compiler
+/// is able to vectorize. The remaining `gcd(left, right) % 8` cycles are moved one by one.
+///
+/// If `gcd(left, right) < 8` there is nothing to chunk and [`ptr_direct_rotate`] is used.
+/// For `left == right` the halves are just swapped with [`swap_halves`](crate::utils::swap_halves).
+/// Non-`Copy` types should use [`ptr_direct_rotate`] too.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+///
+/// ## Example
+///
+/// ```text
+///                               mid
+///   left = 10                   | right = 4
+/// [ 1  2  3  4  5  6  7  8  9 10*11 12 13 14]  tmp = [ 1  2], gcd = 2, chunk of 2
+/// [ ✘  ✘  .  .  1  2  .  .  .  .  .  .  .  .]  tmp = [ 5  6]
+/// [ ✘  ✘  .  .  1  2  .  .  5  6  .  .  .  .]  tmp = [ 9 10]
+/// [ ✘  ✘  .  .  1  2  .  .  5  6  .  .  9 10]  tmp = [13 14]
+/// [ ✘  ✘ 13 14  1  2  .  .  5  6  .  .  9 10]  tmp = [ 3  4]
+/// [ ✘  ✘ 13 14  1  2  3  4  5  6  .  .  9 10]  tmp = [ 7  8]
+/// [ ✘  ✘ 13 14  1  2  3  4  5  6  7  8  9 10]  tmp = [11 12]
+/// [11 12 13 14: 1  .  .  .  .  .  .  .  . 10]
+/// ```
+pub unsafe fn ptr_direct_rotate_chunked<T: Copy>(left: usize, mid: *mut T, right: usize) {
+    const CHUNK: usize = 8;
+
+    if is_zst::<T>() {
+        return;
+    }
+
+    if right <= 2 || left <= 2 {
+        ptr_edge_rotate(left, mid, right);
+        return;
+    }
+
+    if left == right {
+        swap_halves(mid.sub(left), mid, left);
+        return;
+    }
+
+    let gcd = binary_usize(left, right);
+
+    if gcd < CHUNK {
+        ptr_direct_rotate(left, mid, right);
+        return;
+    }
+
+    let start = mid.sub(left);
+
+    let mut rawarray = MaybeUninit::<[T; CHUNK]>::uninit();
+    let tmp = rawarray.as_mut_ptr() as *mut T;
+
+    let mut s = 0;
+
+    while s + CHUNK <= gcd {
+        ptr::copy_nonoverlapping(start.add(s), tmp, CHUNK);
+
+        let mut i = right;
+
+        loop {
+            ptr::swap_nonoverlapping(tmp, start.add(s + i), CHUNK);
+
+            if i >= left {
+                i -= left;
+                if i == 0 {
+                    ptr::copy_nonoverlapping(tmp, start.add(s), CHUNK);
+                    break;
+                }
+            } else {
+                i += right;
+            }
+        }
+
+        s += CHUNK;
+    }
+
+    // finish the rest of the cycles one by one
+    for s in s..gcd {
+        let mut tmp = start.add(s).read();
+        let mut i = s + right;
+
+        loop {
+            tmp = start.add(i).replace(tmp);
+
+            if i >= left {
+                i -= left;
+                if i == s {
+                    start.add(s).write(tmp);
+                    break;
+                }
+            } else {
+                i += right;
+            }
+        }
+    }
+}
+
+/// # Minimum move rotation
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// The cycles of [`ptr_direct_rotate`], but every element is moved into the hole left by the
+/// previous one instead of being swapped with a temporary, and no other algorithm is ever used,
+/// whatever the sizes. Each of the `gcd(left, right)` cycles reads its first element into a
+/// temporary, so there are exactly `left + right + gcd(left, right)` moves.
+///
+/// This is the choice when moving `T` dominates (large structs), unlike [`stable_ptr_rotate`]
+/// and [`ptr_rotate`], which switch to cache-friendlier algorithms with more moves.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+///
+/// ## Example
+///
+/// ```text
+///            mid
+///   left = 3 |     right = 6
+/// [ 1  2  3* 4  5  6  7  8  9]  // tmp = 1
+/// [ 4  2  3  7  5  6  ✘  8  9]  // 4 and 7 are moved into the holes
+/// [ 4  2  3  7  5  6  1  8  9]  // tmp goes into the last hole, next cycle
+/// [ 4 ~~~~~~~~~~~~ 9: 1  2  3]
+/// ```
+pub unsafe fn ptr_min_move_rotate<T>(left: usize, mid: *mut T, right: usize) {
+    if is_zst::<T>() || left == 0 || right == 0 {
+        return;
+    }
+
+    juggle(mid.sub(left), left, left + right, binary_usize(left, right));
+}
+
+/// Cycles of the *Juggling rotation* over `[start, start + len)`: for each of the first `cycles`
+/// positions reads it into a temporary, fills the hole with the element `left` steps further
+/// (modulo `len`) until it comes back, then writes the temporary into the last hole.
+///
+/// Returns the number of holes filled, that is `len - cycles` if `cycles = gcd(left, len)`.
+///
+/// ## Safety
+///
+/// The range must be valid for reading and writing, `0 < left < len` and `cycles` must be
+/// `gcd(left, len)`.
+#[inline(always)]
+pub(crate) unsafe fn juggle<T>(start: *mut T, left: usize, len: usize, cycles: usize) -> usize {
+    let mut moves = 0;
+
+    for s in 0..cycles {
+        let tmp = start.add(s).read();
+        let mut hole = s;
+
+        loop {
+            let mut next = hole + left;
+            if next >= len {
+                next -= len;
+            }
 
-            swap_backward(start, end.sub(left), left);
-
-            left %= right;
-            end = end.sub(left);
-            mid = start.add(left);
-            right -= left;
-        } else {
-            if left <= 2 || left == right {
+            if next == s {
                 break;
             }
 
-            swap_forward(mid, start, right);
-
-            start = start.add(right);
-            right %= left;
-            mid = end.sub(right);
-            left -= right;
+            ptr::copy_nonoverlapping(start.add(next), start.add(hole), 1);
+            hole = next;
+            moves += 1;
         }
-    }
 
-    if left <= 2 || right <= 2 || left == right {
-        ptr_edge_rotate(left, mid, right);
+        start.add(hole).write(tmp);
     }
+
+    moves
 }
 
-/// # Direct aka Juggling aka Dolphin rotation
+/// # Juggling rotation
 ///
 /// Rotates the range `[mid-left, mid+right)` such that the element at
 /// `mid` becomes the first element. Equivalently, rotates the range
 /// `left` elements to the left or `right` elements to the right.
 ///
-/// ## Algorithm
+/// Textbook variant of `ptr_direct_rotate`: every round reads one temporary once, chases the
+/// hole and writes the temporary at the end of the round.
 ///
-/// "In Rust this algorithm is used for small values of `left + right` or for large `T`. The elements
-/// are moved into their final positions one at a time starting at `mid - left` and advancing by `right`
-/// steps modulo `left + right`, such that only one temporary is needed. Eventually, we arrive back at
-/// `mid - left`. However, if `gcd(left + right, right)` is not 1, the above steps skipped over
-/// elements."
+/// ## Algorithm
 ///
-/// "Fortunately, the number of skipped over elements between finalized elements is always equal, so
-/// we can just offset our starting position and do more rounds (the total number of rounds is the
-/// `gcd(left + right, right)` value). The end result is that all elements are finalized once and
-/// only once."
+/// "Also known as the dolphin algorithm. This is a relatively complex
+/// and inefficient way to rotate in-place, though it does so in the
+/// minimal number of moves. Its first known publication was in *1966*.
+/// It computes the greatest common divisor and uses a loop to create
+/// a chain of consecutive swaps." <<https://github.com/scandum/rotate>>
 ///
-/// Its first known publication was in *1966*.
+/// 1. Compute `gcd(left + right, right)`, the number of rounds;
+/// 2. for every round read the first element of the round into a temporary;
+/// 3. fill the hole with the element `left` steps further (modulo `left + right`);
+/// 4. repeat until we come back to the start of the round, then write the temporary.
 ///
 /// ## Safety
 ///
@@ -661,49 +1705,27 @@ pub unsafe fn ptr_helix_rotate<T>(mut left: usize, mut mid: *mut T, mut right: u
 /// ```text
 ///                            mid
 ///           left = 9         |    right = 6
-/// [ 1  2  3  4  5  6: 7  8  9*10 11 12 13 14 15]
-///   |        |        |        |        └──────────────────╮
-///   |        |        |        └──────────────────╮        ┆
-///   |        |        └─────────────────┐         ┆        ┆
-///   |        └─────────────────┐        ┆         ┆        ┆
-///   └─────────────────┐        ┆        ┆         ┆        ┆
-/// ~──────────╮        ┆        ┆        ┆         ┆        ┆
-/// ~─╮        ┆        ┆        ┆        ┆         ┆        ┆
-///   ↓        ↓        ↓        ↓        ↓         ↓        ↓
-/// [10  2  3 13  5  6  1  8  9  4 11 12  7 14 15][10  2  3 13...
-///      |        |        |        |        └──────────────────╮
-///      |        |        |        └──────────────────╮        ┆
-///      |        |        └─────────────────┐         ┆        ┆
-///      |        └─────────────────┐        ┆         ┆        ┆
-///      └─────────────────┐        ┆        ┆         ┆        ┆
-/// ~─────────────╮        ┆        ┆        ┆         ┆        ┆
-/// ~────╮        ┆        ┆        ┆        ┆         ┆        ┆
-///   _  ↓     _  ↓     _  ↓     _  ↓     _  ↓      _  ↓     _  ↓
-/// [10 11  3 13 14  6  1  2  9  4  5 12  7  8 15][10 11  3 13 14...
-///         |        |        |        |        └──────────────────╮
-///         |        |        |        └──────────────────╮        ┆
-///         |        |        └─────────────────┐         ┆        ┆
-///         |        └─────────────────┐        ┆         ┆        ┆
-///         └─────────────────┐        ┆        ┆         ┆        ┆
-/// ~────────────────╮        ┆        ┆        ┆         ┆        ┆
-/// ~───────╮        ┆        ┆        ┆        ┆         ┆        ┆
-///   _  _  ↓  _  _  ↓  _  _  ↓  _  _  ↓  _  _  ↓   _  _  ↓  _  _  ↓
-/// [10  . 12  .  . 15: .  .  3* .  .  6  .  .  9][ .  . 12  .  . 15...
-/// ```
-///
-/// ```text
-///                            mid
-///           left = 9         |    right = 6
-/// [ 1  2  3  4  5  6: 7  8  9* a  b  c  d  e  f]
-///   └─────|  └─────|  └─────|  └─────|  └─────|
-///  ~╮     └───────────╮     |        └────────────╮
-///   |              |  |     └───────────╮     |   |
-///  ~| ───────╮     └───────────╮        |     └───| ───────╮
-///   ↓        ↓        ↓        ↓        ↓         ↓        ↓
-/// [ a ~~~ c  d ~~~ f  1 ~~~ 3  4 ~~~ 6  7 ~~~ 9][ a ~~~ c  d ~~~ f...
+/// [ 1  2  3  4  5  6: 7  8  9*10 11 12 13 14 15]          // round
+///   └─────────────────────────────────────────────────┐
+/// [ ✘  2  3  4  5  6  7  8  9 10 11 12 13 14 15]    [ 1]
+///   ┌──────────────────────────┘
+/// [10  2  3  4  5  6  7  8  9  ✘ 11 12 13 14 15]    [ 1]
+///            └─────────────────┐
+/// [10  2  3  ✘  5  6  7  8  9  4 11 12 13 14 15]    [ 1]
+///            ┌──────────────────────────┘
+/// [10  2  3 13  5  6  7  8  9  4 11 12  ✘ 14 15]    [ 1]
+///                     └─────────────────┐
+/// [10  2  3 13  5  6  ✘  8  9  4 11 12  7 14 15]    [ 1]
+///                     ┌───────────────────────────────┘
+/// [10  2  3 13  5  6  1  8  9  4 11 12  7 14 15]          // round
+/// [10 11  3 13 14  6  1  2  9  4  5 12  7  8 15]          // round
+/// [10 11 12 13 14 15: 1  2  3* 4  5  6  7  8  9]
 /// ```
-pub unsafe fn ptr_direct_rotate<T>(left: usize, mid: *mut T, right: usize) {
-    // N.B. the below algorithms can fail if these cases are not checked
+pub unsafe fn ptr_juggling_rotate<T>(left: usize, mid: *mut T, right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
     if right <= 2 || left <= 2 {
         ptr_edge_rotate(left, mid, right);
         return;
@@ -712,65 +1734,13 @@ pub unsafe fn ptr_direct_rotate<T>(left: usize, mid: *mut T, right: usize) {
     let start = mid.sub(left);
 
     if left == right {
-        ptr::swap_nonoverlapping(start, mid, left);
+        swap_halves(start, mid, left);
         return;
     }
 
-    // beginning of first round
-    let mut tmp: T = start.read();
-    let mut i = right;
-
-    // `gcd` can be found before hand by calculating `gcd(left + right, right)`,
-    // but it is faster to do one loop which calculates the gcd as a side effect, then
-    // doing the rest of the chunk
-    let mut gcd = right;
-
-    // benchmarks reveal that it is faster to swap temporaries all the way through instead
-    // of reading one temporary once, copying backwards, and then writing that temporary at
-    // the very end. This is possibly due to the fact that swapping or replacing temporaries
-    // uses only one memory address in the loop instead of needing to manage two.
-    loop {
-        std::mem::swap(&mut tmp, &mut *start.add(i));
-        // tmp = start.add(i).replace(tmp);
-
-        // instead of incrementing `i` and then checking if it is outside the bounds, we
-        // check if `i` will go outside the bounds on the next increment. This prevents
-        // any wrapping of pointers or `usize`.
-        if i >= left {
-            i -= left;
-            if i == 0 {
-                // end of first round
-                start.write(tmp);
-                break;
-            }
-            // this conditional must be here if `left + right >= 15`
-            if i < gcd {
-                gcd = i;
-            }
-        } else {
-            i += right;
-        }
-    }
-
-    // finish the chunk with more rounds
-    for s in 1..gcd {
-        tmp = start.add(s).read();
-        i = s + right;
+    let size = left + right;
 
-        loop {
-            std::mem::swap(&mut tmp, &mut *start.add(i));
-            // tmp = start.add(i).replace(tmp);
-            if i >= left {
-                i -= left;
-                if i == s {
-                    start.add(s).write(tmp);
-                    break;
-                }
-            } else {
-                i += right;
-            }
-        }
-    }
+    juggle(start, left, size, gcd::binary_usize(size, right));
 }
 
 /// # Contrev (Conjoined triple reversal) rotation
@@ -781,7 +1751,8 @@ pub unsafe fn ptr_direct_rotate<T>(left: usize, mid: *mut T, right: usize) {
 ///
 /// ## Safety
 ///
-/// The specified range must be valid for reading and writing.
+/// The specified range must be valid for reading and writing. In particular, `left + right` can't
+/// exceed [`max_rotation_len`], which is checked in debug builds.
 ///
 /// ## Algorithm
 ///
@@ -876,157 +1847,317 @@ pub unsafe fn ptr_direct_rotate<T>(left: usize, mid: *mut T, right: usize) {
 /// [ a ~~~~~~~~~ e  f  g: 1* 2  3  4 ~~~~~~~~~ 8]
 /// ```
 pub unsafe fn ptr_contrev_rotate<T>(left: usize, mid: *mut T, right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    debug_check_len::<T>(left, right, "ptr_contrev_rotate");
+
     if left <= 2 || right <= 2 {
         ptr_edge_rotate(left, mid, right);
         return;
     }
 
     if left == right {
-        ptr::swap_nonoverlapping(mid, mid.sub(left), right);
+        swap_halves(mid, mid.sub(left), right);
     } else {
-        let (mut ls, mut le) = (mid.sub(left), mid.sub(1));
-        let (mut rs, mut re) = (mid, mid.add(right).sub(1));
+        let (ls, re) = contrev_permute(left, mid, right);
 
-        let half_min = cmp::min(left, right) / 2;
-        let half_max = cmp::max(left, right) / 2;
+        // for _ in 0..re.offset_from(ls).abs() / 2 { // (re, ls)
+        // ls.write(
+        // re.replace(ls.read())
+        // );
+        // ls = ls.add(1);
+        // re = re.sub(1);
+        // }
 
-        for _ in 0..half_min {
-            // Permutation (ls, le, re, rs)
-            ls.write(rs.replace(re.replace(le.replace(ls.read()))));
+        let center = core::slice::from_raw_parts_mut(ls, re.offset_from(ls).unsigned_abs() + 1);
+        center.reverse();
+    }
+}
+
+/// Permutation loops of the *Contrev rotation*: reverses both sides and the whole range at once
+/// until the smallest side is exhausted. Returns the bounds `(ls, re)` of the center that is
+/// left to reverse.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing, `left != right` and both sides
+/// have more than `2` elements.
+#[inline(always)]
+unsafe fn contrev_permute<T>(left: usize, mid: *mut T, right: usize) -> (*mut T, *mut T) {
+    let (mut ls, mut le) = (mid.sub(left), mid.sub(1));
+    let (mut rs, mut re) = (mid, mid.add(right).sub(1));
+
+    let half_min = cmp::min(left, right) / 2;
+    let half_max = cmp::max(left, right) / 2;
+
+    for _ in 0..half_min {
+        // Permutation (ls, le, re, rs)
+        ls.write(rs.replace(re.replace(le.replace(ls.read()))));
+        ls = ls.add(1);
+        le = le.sub(1);
+        rs = rs.add(1);
+        re = re.sub(1);
+    }
+
+    if left > right {
+        for _ in 0..half_max - half_min {
+            // (ls, le, re)
+            ls.write(re.replace(le.replace(ls.read())));
             ls = ls.add(1);
             le = le.sub(1);
+            re = re.sub(1);
+        }
+    } else {
+        for _ in 0..half_max - half_min {
+            // (rs, re, ls)
+            ls.write(rs.replace(re.replace(ls.read())));
+            ls = ls.add(1);
             rs = rs.add(1);
             re = re.sub(1);
         }
+    }
 
-        if left > right {
-            for _ in 0..half_max - half_min {
-                // (ls, le, re)
-                ls.write(re.replace(le.replace(ls.read())));
-                ls = ls.add(1);
-                le = le.sub(1);
-                re = re.sub(1);
+    (ls, re)
+}
+
+/// # Contrev (Conjoined triple reversal) rotation (Right)
+///
+/// Rotates the range `[end - rest - count, end)` right by `count` elements: the last `count`
+/// elements move to the front, the first `rest` elements move to the end.
+///
+/// Same as [`ptr_contrev_rotate`]`(rest, end.sub(count), count)`, but in "rotate right" terms.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+///
+/// ## Example
+///
+/// ```text
+///                    count = 4
+/// [ 1  2  3  4  5* 6  7  8  9]  // rotate right
+/// [ 6  7  8  9: 1  2  3  4  5]
+/// ```
+pub unsafe fn ptr_contrev_rotate_right<T>(count: usize, end: *mut T, rest: usize) {
+    ptr_contrev_rotate(rest, end.sub(count), count);
+}
+
+/// # Contrev (Conjoined triple reversal) rotation (v2)
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// Same as [`ptr_contrev_rotate`], but the center left after the permutation loops is reversed
+/// with an explicit two-pointer `ptr::swap` loop instead of `<[T]>::reverse`, to tell whether the
+/// final reversal or the permutation loops dominate (`bench_contrev_center`).
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+pub unsafe fn ptr_contrev_rotate_v2<T>(left: usize, mid: *mut T, right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    if left <= 2 || right <= 2 {
+        ptr_edge_rotate(left, mid, right);
+        return;
+    }
+
+    if left == right {
+        swap_halves(mid, mid.sub(left), right);
+    } else {
+        let (mut ls, mut re) = contrev_permute(left, mid, right);
+
+        while ls < re {
+            ptr::swap(ls, re);
+            ls = ls.add(1);
+            re = re.sub(1);
+        }
+    }
+}
+
+/// # Harmony rotation
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+///
+/// ## Algorithm
+///
+/// Adaptive rotation that picks the algorithm by the size of `T` and the length of the array.
+///
+/// `size_of(T) <= 1 * usize` case:
+///
+/// * For the array with `<= 14` elements we use *direct rotation*;
+///
+/// * `<= 24` elements:
+///   * `left < right` the *reversal rotation* is used;
+///   * otherwise, *direct rotation*.
+///
+/// * `> 24` elements we use *reversal rotation*.
+///
+/// `size_of(T) > 1 * usize` case:
+///
+/// * For `size_of(T) > 4 * usize` we use *direct rotation*, since it moves every element only once;
+///
+/// * otherwise, *contrev rotation*.
+///
+/// Cases `left <= 2`, `right <= 2` and `left == right` are handled by `ptr_edge_rotate`.
+///
+/// *Algorithm 1* (*Direct*) is used for small values of `left + right` or for large `T`. The elements
+/// are moved into their final positions one at a time starting at `mid - left` and advancing by `right`
+/// steps modulo `left + right`, such that only one temporary is needed.
+///
+/// *Algorithm 2* (*Reversal*) reverses the left side, the right side and then the whole array. It is
+/// easily vectorized, so it outperforms *Algorithm 1* once `left + right` becomes large enough.
+pub unsafe fn ptr_harmony_rotate<T>(left: usize, mid: *mut T, right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    if left <= 2 || right <= 2 || left == right {
+        ptr_edge_rotate(left, mid, right);
+        return;
+    }
+
+    let t_size = core::mem::size_of::<T>();
+    let size = left + right;
+
+    if t_size <= core::mem::size_of::<usize>() {
+        if size <= 14 {
+            ptr_direct_rotate(left, mid, right);
+        } else if size <= 24 {
+            if left < right {
+                ptr_reversal_rotate(left, mid, right);
+            } else {
+                ptr_direct_rotate(left, mid, right);
+            }
+        } else {
+            ptr_reversal_rotate(left, mid, right);
+        }
+    } else if t_size > core::mem::size_of::<[usize; 4]>() {
+        ptr_direct_rotate(left, mid, right);
+    } else {
+        ptr_contrev_rotate(left, mid, right);
+    }
+}
+
+/// # Algorithm 1 rotation
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. This is *Algorithm 1* of [`stable_ptr_rotate`] exposed on its own, without the size
+/// and type dispatch, so that it can be benchmarked in isolation.
+///
+/// ## Algorithm
+///
+/// The elements are moved into their final positions one at a time starting at `mid - left` and
+/// advancing by `right` steps modulo `left + right`. The first round also computes
+/// `gcd(left + right, right)` as a side effect, which is the total number of rounds.
+///
+/// See [`stable_ptr_rotate`] for a diagram.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+pub unsafe fn ptr_algo1_rotate<T>(left: usize, mid: *mut T, right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    if (right == 0) || (left == 0) {
+        return;
+    }
+    // SAFETY: callers must ensure `mid - left` is valid for reading and writing.
+    let x = unsafe { mid.sub(left) };
+    // beginning of first round
+    // SAFETY: see previous comment.
+    let mut tmp: T = unsafe { x.read() };
+    let mut i = right;
+    // `gcd` can be found before hand by calculating `gcd(left + right, right)`,
+    // but it is faster to do one loop which calculates the gcd as a side effect, then
+    // doing the rest of the chunk
+    let mut gcd = right;
+    // benchmarks reveal that it is faster to swap temporaries all the way through instead
+    // of reading one temporary once, copying backwards, and then writing that temporary at
+    // the very end. This is possibly due to the fact that swapping or replacing temporaries
+    // uses only one memory address in the loop instead of needing to manage two.
+    loop {
+        // [long-safety-expl]
+        // SAFETY: callers must ensure `[left, left+mid+right)` are all valid for reading and
+        // writing.
+        //
+        // - `i` start with `right` so `mid-left <= x+i = x+right = mid-left+right < mid+right`
+        // - `i <= left+right-1` is always true
+        //   - if `i < left`, `right` is added so `i < left+right` and on the next
+        //     iteration `left` is removed from `i` so it doesn't go further
+        //   - if `i >= left`, `left` is removed immediately and so it doesn't go further.
+        // - overflows cannot happen for `i` since the function's safety contract ask for
+        //   `mid+right-1 = x+left+right` to be valid for writing
+        // - underflows cannot happen because `i` must be bigger or equal to `left` for
+        //   a subtraction of `left` to happen.
+        //
+        // So `x+i` is valid for reading and writing if the caller respected the contract
+        tmp = unsafe { x.add(i).replace(tmp) };
+        // instead of incrementing `i` and then checking if it is outside the bounds, we
+        // check if `i` will go outside the bounds on the next increment. This prevents
+        // any wrapping of pointers or `usize`.
+        if i >= left {
+            i -= left;
+            if i == 0 {
+                // end of first round
+                // SAFETY: tmp has been read from a valid source and x is valid for writing
+                // according to the caller.
+                unsafe { x.write(tmp) };
+                break;
+            }
+            // this conditional must be here if `left + right >= 15`
+            if i < gcd {
+                gcd = i;
             }
         } else {
-            for _ in 0..half_max - half_min {
-                // (rs, re, ls)
-                ls.write(rs.replace(re.replace(ls.read())));
-                ls = ls.add(1);
-                rs = rs.add(1);
-                re = re.sub(1);
+            i += right;
+        }
+    }
+    // finish the chunk with more rounds
+    for start in 1..gcd {
+        // SAFETY: `gcd` is at most equal to `right` so all values in `1..gcd` are valid for
+        // reading and writing as per the function's safety contract, see [long-safety-expl]
+        // above
+        tmp = unsafe { x.add(start).read() };
+        // [safety-expl-addition]
+        //
+        // Here `start < gcd` so `start < right` so `i < right+right`: `right` being the
+        // greatest common divisor of `(left+right, right)` means that `left = right` so
+        // `i < left+right` so `x+i = mid-left+i` is always valid for reading and writing
+        // according to the function's safety contract.
+        i = start + right;
+        loop {
+            // SAFETY: see [long-safety-expl] and [safety-expl-addition]
+            tmp = unsafe { x.add(i).replace(tmp) };
+            if i >= left {
+                i -= left;
+                if i == start {
+                    // SAFETY: see [long-safety-expl] and [safety-expl-addition]
+                    unsafe { x.add(start).write(tmp) };
+                    break;
+                }
+            } else {
+                i += right;
             }
         }
-
-        // for _ in 0..re.offset_from(ls).abs() / 2 { // (re, ls)
-        // ls.write(
-        // re.replace(ls.read())
-        // );
-        // ls = ls.add(1);
-        // re = re.sub(1);
-        // }
-
-        let center = slice::from_raw_parts_mut(ls, re.offset_from(ls).unsigned_abs() + 1);
-        center.reverse();
     }
 }
 
-// /// # Harmony rotation
-// ///
-// /// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
-// /// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
-// /// right.
-// ///
-// /// ## Safety
-// ///
-// /// The specified range must be valid for reading and writing.
-// ///
-// /// ## Algorithm
-// ///
-// /// `size_of(T) <= 1 * usize' case:
-// ///
-// /// Depending of the size:
-// ///
-// /// * For the array with `<= 14` elements (`size_of(T) <= 1 * usize') we use *direct rotation*;
-// ///
-// /// * `> 14` elements:
-// /// ** `left < right` the *reversal rotation is used*;
-// /// ** otherwise, *direct rotation*.
-// ///
-// /// * `> 20` elements we use *reversal rotation*.
-// ///
-// /// *Algorithm 1* (*Direct*) is used for small values of `left + right` or for large `T`. The elements
-// /// are moved into their final positions one at a time starting at `mid - left` and advancing by `right`
-// /// steps modulo `left + right`, such that only one temporary is needed. Eventually, we arrive back at
-// /// `mid - left`. However, if `gcd(left + right, right)` is not 1, the above steps skipped over
-// /// elements. For example:
-// ///
-// /// *Algorithm 2* (*AUX*) is used if `left + right` is large but `min(left, right)` is small enough to
-// /// fit onto a stack buffer. The `min(left, right)` elements are copied onto the buffer, `memmove`
-// /// is applied to the others, and the ones on the buffer are moved back into the hole on the
-// /// opposite side of where they originated.
-// ///
-// /// Algorithms that can be vectorized outperform the above once `left + right` becomes large enough.
-// /// *Algorithm 1* can be vectorized by chunking and performing many rounds at once, but there are too
-// /// few rounds on average until `left + right` is enormous, and the worst case of a single
-// /// round is always there. Instead, *algorithm 3* (*GM*) utilizes repeated swapping of
-// /// `min(left, right)` elements until a smaller rotate problem is left.
-// ///
-// /// ```text
-// ///                                  mid
-// ///              left = 11           |  right = 4
-// /// [ 5  6  7  8: 9 10 11 12 13 14 15* 1  2  3  4]   swap
-// ///                        └────────┴/\┴────────┘
-// ///                        ┌────────┬~~┬────────┐
-// /// [ 5  .  .  .  .  . 11  1 ~~~~~~ 4 12 13 14 15]
-// ///
-// /// [ 5  .  7  1  2  3  4  8  9 10 11 12 ~~~~~ 15    swap
-// ///            └────────┴/\┴────────┘
-// ///            ┌────────┬~~┬────────┐
-// /// [ 5  .  7  8: 9  . 11: 1 ~~~~~~ 4*12  .  . 15
-// /// we cannot swap any more, but a smaller rotation problem is left to solve
-// /// ```
-// ///
-// /// when `left < right` the swapping happens from the left instead.
-// pub unsafe fn ptr_harmony_rotate<T>(mut left: usize, mut mid: *mut T, mut right: usize) {
-//     type BufType = [usize; 32];
-
-//     // if T::IS_ZST {
-//     // return;
-//     // }
-
-//     let t_size = std::mem::size_of::<T>();
-
-//     loop {
-//         if right <= 1 || left <= 1 {
-//             ptr_edge_rotate(left, mid, right);
-//             return;
-//         }
-
-//         let start = mid.sub(left);
-//
-//         if left == right {
-//             ptr::swap_nonoverlapping(start, mid, left);
-//         }
-
-//         let size = left + right;
-
-//         if t_size <= std::mem::size_of::<usize>() {
-//             if size <= 14 {
-//                 ptr_direct_rotate(left, mid, right);
-//             } else if size <= 24 {
-//                 if left < right {
-//                     ptr_reversal_rotate(left, mid, right);
-//                 } else {
-//                     ptr_direct_rotate(left, mid, right);
-//                 }
-//             } else if size < 40 {
-//                 ptr_reversal_rotate(left, mid, right);
-//             }
-//         } else {
-//         }
-//     }
-// }
-
 /// # Default (Stable) rotation
 ///
 /// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
@@ -1100,314 +2231,852 @@ pub unsafe fn ptr_contrev_rotate<T>(left: usize, mid: *mut T, right: usize) {
 /// `min(left, right)` elements until a smaller rotate problem is left.
 ///
 /// ```text
-///                                  mid
-///              left = 11           |  right = 4
-/// [ 5  6  7  8: 9 10 11 12 13 14 15* 1  2  3  4]   swap
-///                        └────────┴/\┴────────┘
-///                        ┌────────┬~~┬────────┐
-/// [ 5  .  .  .  .  . 11  1 ~~~~~~ 4 12 13 14 15]
+///                                  mid
+///              left = 11           |  right = 4
+/// [ 5  6  7  8: 9 10 11 12 13 14 15* 1  2  3  4]   swap
+///                        └────────┴/\┴────────┘
+///                        ┌────────┬~~┬────────┐
+/// [ 5  .  .  .  .  . 11  1 ~~~~~~ 4 12 13 14 15]
+///
+/// [ 5  .  7  1  2  3  4  8  9 10 11 12 ~~~~~ 15    swap
+///            └────────┴/\┴────────┘
+///            ┌────────┬~~┬────────┐
+/// [ 5  .  7  8: 9  . 11: 1 ~~~~~~ 4*12  .  . 15
+/// we cannot swap any more, but a smaller rotation problem is left to solve
+/// ```
+///
+/// when `left < right` the swapping happens from the left instead.
+pub unsafe fn stable_ptr_rotate<T>(mut left: usize, mut mid: *mut T, mut right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    //Taken from https://github.com/rust-lang/rust/blob/11d96b59307b1702fffe871bfc2d0145d070881e/library/core/src/slice/rotate.rs .
+
+    loop {
+        // N.B. the below algorithms can fail if these cases are not checked
+        if (right == 0) || (left == 0) {
+            return;
+        }
+
+        if (left + right < 24) || (core::mem::size_of::<T>() > core::mem::size_of::<[usize; 4]>()) {
+            // Algorithm 1
+            // Microbenchmarks indicate that the average performance for random shifts is better all
+            // the way until about `left + right == 32`, but the worst case performance breaks even
+            // around 16. 24 was chosen as middle ground. If the size of `T` is larger than 4
+            // `usize`s, this algorithm also outperforms other algorithms.
+            // SAFETY: callers must ensure `[mid-left, mid+right)` is valid for reading and writing.
+            unsafe { algo1(left, mid, right) };
+            return;
+        // `T` is not a zero-sized type, so it's okay to divide by its size.
+        } else if cmp::min(left, right)
+            <= core::mem::size_of::<StableBufType>() / core::mem::size_of::<T>()
+        {
+            // Algorithm 2
+            // SAFETY: `min(left, right)` elements fit onto the stack buffer.
+            unsafe { algo2_aux(left, mid, right) };
+            return;
+        } else {
+            // Algorithm 3
+            // SAFETY: callers must ensure `[mid-left, mid+right)` is valid for reading and writing.
+            (left, mid, right) = unsafe { algo3_gm(left, mid, right) };
+        }
+    }
+}
+
+/// Stack buffer of *Algorithm 2* of [`stable_ptr_rotate`].
+type StableBufType = [usize; 32];
+
+/// # Rotate config
+///
+/// Thresholds of the adaptive dispatch of [`stable_ptr_rotate`], to be tuned for a particular
+/// `T` and data distribution and passed to [`ptr_rotate_cfg`]. The [`Default`] values are the
+/// ones [`stable_ptr_rotate`] uses.
+///
+/// ## Example
+///
+/// ```
+/// use rust_rotations::{ptr_rotate_cfg, RotateConfig};
+///
+/// let cfg = RotateConfig::default().direct_max(32);
+/// let mut v = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+///
+/// unsafe { ptr_rotate_cfg(4, v.as_mut_ptr().add(4), 5, &cfg) };
+///
+/// assert_eq!(v, [5, 6, 7, 8, 9, 1, 2, 3, 4]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RotateConfig {
+    /// *Algorithm 1* (*Direct*) is used if `left + right < direct_max`. Default: `24`.
+    pub direct_max: usize,
+    /// *Algorithm 2* (*AUX*) is used if `min(left, right)` elements take at most
+    /// `aux_max_overlap` bytes. Capped by the stack buffer of `32 * size_of::<usize>()` bytes,
+    /// which is also the default.
+    pub aux_max_overlap: usize,
+    /// *Algorithm 1* (*Direct*) is used if `size_of::<T>() > large_t_bytes`. Default:
+    /// `4 * size_of::<usize>()`.
+    pub large_t_bytes: usize,
+}
+
+impl Default for RotateConfig {
+    fn default() -> Self {
+        Self {
+            direct_max: 24,
+            aux_max_overlap: core::mem::size_of::<StableBufType>(),
+            large_t_bytes: core::mem::size_of::<[usize; 4]>(),
+        }
+    }
+}
+
+impl RotateConfig {
+    /// Sets [`direct_max`](RotateConfig#structfield.direct_max).
+    #[must_use]
+    pub const fn direct_max(mut self, direct_max: usize) -> Self {
+        self.direct_max = direct_max;
+        self
+    }
+
+    /// Sets [`aux_max_overlap`](RotateConfig#structfield.aux_max_overlap).
+    #[must_use]
+    pub const fn aux_max_overlap(mut self, aux_max_overlap: usize) -> Self {
+        self.aux_max_overlap = aux_max_overlap;
+        self
+    }
+
+    /// Sets [`large_t_bytes`](RotateConfig#structfield.large_t_bytes).
+    #[must_use]
+    pub const fn large_t_bytes(mut self, large_t_bytes: usize) -> Self {
+        self.large_t_bytes = large_t_bytes;
+        self
+    }
+}
+
+/// # Stable rotation (Configurable)
+///
+/// Same as [`stable_ptr_rotate`], but the thresholds between its three algorithms are taken from
+/// `cfg` instead of being hardcoded. With `RotateConfig::default()` it does exactly what
+/// [`stable_ptr_rotate`] does.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+pub unsafe fn ptr_rotate_cfg<T>(
+    mut left: usize,
+    mut mid: *mut T,
+    mut right: usize,
+    cfg: &RotateConfig,
+) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    let aux_max = cmp::min(cfg.aux_max_overlap, core::mem::size_of::<StableBufType>());
+
+    loop {
+        if (right == 0) || (left == 0) {
+            return;
+        }
+
+        if (left + right < cfg.direct_max) || (core::mem::size_of::<T>() > cfg.large_t_bytes) {
+            algo1(left, mid, right);
+            return;
+        } else if cmp::min(left, right) <= aux_max / core::mem::size_of::<T>() {
+            // SAFETY: `aux_max` is capped by the size of the stack buffer.
+            algo2_aux(left, mid, right);
+            return;
+        } else {
+            (left, mid, right) = algo3_gm(left, mid, right);
+        }
+    }
+}
+
+/// *Algorithm 1* of [`stable_ptr_rotate`]: the hot path for short ranges and large `T`.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+#[inline(always)]
+unsafe fn algo1<T>(left: usize, mid: *mut T, right: usize) {
+    ptr_algo1_rotate(left, mid, right);
+}
+
+/// *Algorithm 2* of [`stable_ptr_rotate`]. Kept out of line, so the stack buffer does not
+/// grow the frame of the caller.
+///
+/// ## Safety
+///
+/// 1. The specified range must be valid for reading and writing;
+/// 2. `min(left, right)` elements of `T` must fit into `StableBufType`.
+#[inline(never)]
+unsafe fn algo2_aux<T>(left: usize, mid: *mut T, right: usize) {
+    // The `[T; 0]` here is to ensure this is appropriately aligned for T
+    let mut rawarray = MaybeUninit::<(StableBufType, [T; 0])>::uninit();
+    let buf = rawarray.as_mut_ptr() as *mut T;
+    // SAFETY: `mid-left <= mid-left+right < mid+right`
+    let dim = unsafe { mid.sub(left).add(right) };
+    if left <= right {
+        // SAFETY:
+        //
+        // 1) The precondition about the sizes ensures `[mid-left; left]` will fit in
+        //    `buf` without overflow and `buf` was created just above and so cannot be
+        //    overlapped with any value of `[mid-left; left]`
+        // 2) [mid-left, mid+right) are all valid for reading and writing and we don't care
+        //    about overlaps here.
+        // 3) The `if` condition about `left <= right` ensures writing `left` elements to
+        //    `dim = mid-left+right` is valid because:
+        //    - `buf` is valid and `left` elements were written in it in 1)
+        //    - `dim+left = mid-left+right+left = mid+right` and we write `[dim, dim+left)`
+        unsafe {
+            // 1)
+            ptr::copy_nonoverlapping(mid.sub(left), buf, left);
+            // 2)
+            ptr::copy(mid, mid.sub(left), right);
+            // 3)
+            ptr::copy_nonoverlapping(buf, dim, left);
+        }
+    } else {
+        // SAFETY: same reasoning as above but with `left` and `right` reversed
+        unsafe {
+            ptr::copy_nonoverlapping(mid, buf, right);
+            ptr::copy(mid.sub(left), dim, left);
+            ptr::copy_nonoverlapping(buf, mid.sub(left), right);
+        }
+    }
+}
+
+/// *Algorithm 3* of [`stable_ptr_rotate`]: swaps `min(left, right)` elements until the smallest
+/// side no longer fits, and returns the smaller rotation problem `(left, mid, right)` left to
+/// solve. Only reached for large ranges, where the call is negligible.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+#[cold]
+#[inline(never)]
+unsafe fn algo3_gm<T>(
+    mut left: usize,
+    mut mid: *mut T,
+    mut right: usize,
+) -> (usize, *mut T, usize) {
+    if left >= right {
+        // Algorithm 3
+        //
+        //           left = 9         mid    right = 6
+        // [ 1  2  3  4  5  6  7  8  9,10 11 12 13 14 15]
+        //            └──────────────┴──┬──────────────┐
+        //    l = 3  mid                |    r = 6     |
+        // [ 1  2  3,10 11 12 13 14 15  4  5  6  7  8  9]
+        //
+        // There is an alternate way of swapping that involves finding where the last swap
+        // of this algorithm would be, and swapping using that last chunk instead of swapping
+        // adjacent chunks like this algorithm is doing, but this way is still faster.
+        loop {
+            // SAFETY:
+            // `left >= right` so `[mid-right, mid+right)` is valid for reading and writing
+            // Subtracting `right` from `mid` each turn is counterbalanced by the addition and
+            // check after it.
+            unsafe {
+                ptr::swap_nonoverlapping(mid.sub(right), mid, right);
+                mid = mid.sub(right);
+            }
+            left -= right;
+            if left < right {
+                break;
+            }
+        }
+    } else {
+        // Algorithm 3, `left < right`
+        //
+        //  left = 3 mid                    right = 6
+        // [ 1  2  3,10 11 12 13 14 15 ,4  5  6  7  8  9]
+        //   └─────┴──┬─────┐
+        //   l = 3    |     | mid                r = 3
+        // [10 11 12  1  2  3,13 14 15  4  5  6, 7  8  9]
+        //   l = 3    └─────┴──┬─────┐ mid                r = 0
+        // [10 11 12 13 14 15  1  2  3 ,4  5  6  7  8  9],
+        loop {
+            // SAFETY: `[mid-left, mid+left)` is valid for reading and writing because
+            // `left < right` so `mid+left < mid+right`.
+            // Adding `left` to `mid` each turn is counterbalanced by the subtraction and check
+            // after it.
+            unsafe {
+                ptr::swap_nonoverlapping(mid.sub(left), mid, left);
+                mid = mid.add(left);
+            }
+            right -= left;
+            if right < left {
+                break;
+            }
+        }
+    }
+
+    (left, mid, right)
+}
+
+/// # Rotation
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// Recommended default. Unlike [`stable_ptr_rotate`], which mirrors `core`, it picks the algorithm
+/// by the crossover points found with `benches/rotations.rs`.
+///
+/// ## Algorithm
+///
+/// 1. `left == right` -- a single `ptr::swap_nonoverlapping` of the two sides;
+/// 2. `left + right <= 24` -- [`ptr_direct_rotate`] (`bench_short`: it wins on all short
+///    slices);
+/// 3. `size_of::<T>() > 4 * size_of::<usize>()` -- [`ptr_direct_rotate`] (`bench_short` and
+///    `bench_contrev` with `N = 5` and above: the moves dominate, and it moves each element once);
+/// 4. otherwise -- [`ptr_contrev_rotate`] (`bench_contrev`: the fastest in-place rotation for
+///    medium and large slices of small elements).
+///
+/// Sides `<= 2` are always handled by [`ptr_edge_rotate`].
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+///
+/// ## Example
+///
+/// ```text
+///                mid
+///   left = 5     |  right = 5
+/// [ 1  2  3  4  5* 6  7  8  9 10]  // swap_nonoverlapping
+///   └───────────┴/\┴───────────┘
+///   ┌───────────┬~~┬───────────┐
+/// [ 6 ~~~~~~~~ 10* 1 ~~~~~~~~~ 5]
+/// ```
+pub unsafe fn ptr_rotate<T>(left: usize, mid: *mut T, right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    match recommend::<T>(left, right) {
+        RotationKind::Edge => ptr_edge_rotate(left, mid, right),
+        RotationKind::Direct => ptr_direct_rotate(left, mid, right),
+        _ => ptr_contrev_rotate(left, mid, right),
+    }
+}
+
+/// # Rotation (`Copy`)
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// [`ptr_rotate`] for `T: Copy`. Rust has no stable specialization, so this is a separate entry
+/// with a `Copy` bound rather than a branch of [`ptr_rotate`].
+///
+/// ## Algorithm
+///
+/// 1. sides `<= 2` -- [`ptr_edge_rotate`];
+/// 2. `gcd(left, right) >= 8` -- [`ptr_direct_rotate_chunked`]: every element is moved once, and
+///    `8` cycles at a time with wide copies;
+/// 3. otherwise -- [`ptr_rotate`].
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+pub unsafe fn ptr_rotate_copy<T: Copy>(left: usize, mid: *mut T, right: usize) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    if left <= 2 || right <= 2 {
+        ptr_edge_rotate(left, mid, right);
+    } else if gcd::binary_usize(left, right) >= 8 {
+        ptr_direct_rotate_chunked(left, mid, right);
+    } else {
+        ptr_rotate(left, mid, right);
+    }
+}
+
+/// # Wrapping rotation
+///
+/// Rotates the region `[buf, buf + len)` such that the element at `buf + start` becomes the first
+/// element, i.e. rotates it left by `start` elements. Useful for windows that conceptually wrap
+/// around, like ring buffers.
+///
+/// Same as [`stable_ptr_rotate`]`(start, buf.add(start), len - start)`, but takes the region as
+/// a single length and an offset instead of `left`, `mid` and `right`.
+///
+/// ## Safety
+///
+/// 1. The region `[buf, buf + len)` must be valid for reading and writing;
+/// 2. `start <= len`.
+///
+/// Condition 2 is checked in debug builds.
+///
+/// ## Example
+///
+/// ```text
+///   buf           start = 5
+/// [ 1  2  3  4  5* 6  7  8  9]  // len = 9
+/// [ 6  7  8  9: 1  2  3  4  5]
+/// ```
+pub unsafe fn ptr_rotate_wrap<T>(buf: *mut T, len: usize, start: usize) {
+    debug_assert!(
+        start <= len,
+        "ptr_rotate_wrap: start = {start} > len = {len}"
+    );
+
+    stable_ptr_rotate(start, buf.add(start), len - start);
+}
+
+/// # Direction
+///
+/// Direction of the rotation for [`ptr_rotate_dir`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// The first `count` elements move to the end.
+    Left,
+    /// The last `count` elements move to the front.
+    Right,
+}
+
+/// # Rotation with direction
+///
+/// Rotates the region `[buf, buf + len)` by `count` elements to the left or to the right, as
+/// `<[T]>::rotate_left(count)` and `<[T]>::rotate_right(count)` do.
+///
+/// Computes `left` and `right` for [`stable_ptr_rotate`] itself, so there is no need to swap
+/// them and offset `mid` by hand to rotate right.
+///
+/// ## Safety
+///
+/// 1. The region `[buf, buf + len)` must be valid for reading and writing;
+/// 2. `count <= len`.
+///
+/// Condition 2 is checked in debug builds.
+///
+/// ## Example
+///
+/// ```text
+///   buf     count = 3, dir = Left
+/// [ 1  2  3* 4  5  6  7  8  9]  // len = 9
+/// [ 4  5  6  7  8  9: 1  2  3]
 ///
-/// [ 5  .  7  1  2  3  4  8  9 10 11 12 ~~~~~ 15    swap
-///            └────────┴/\┴────────┘
-///            ┌────────┬~~┬────────┐
-/// [ 5  .  7  8: 9  . 11: 1 ~~~~~~ 4*12  .  . 15
-/// we cannot swap any more, but a smaller rotation problem is left to solve
+///   buf     count = 3, dir = Right
+/// [ 1  2  3  4  5  6* 7  8  9]  // len = 9
+/// [ 7  8  9: 1  2  3  4  5  6]
 /// ```
-///
-/// when `left < right` the swapping happens from the left instead.
-pub unsafe fn stable_ptr_rotate<T>(mut left: usize, mut mid: *mut T, mut right: usize) {
-    //Taken from https://github.com/rust-lang/rust/blob/11d96b59307b1702fffe871bfc2d0145d070881e/library/core/src/slice/rotate.rs .
-
-    type BufType = [usize; 32];
-
-    // if T::IS_ZST {
-    // return;
-    // }
+pub unsafe fn ptr_rotate_dir<T>(count: usize, buf: *mut T, len: usize, dir: Direction) {
+    debug_assert!(
+        count <= len,
+        "ptr_rotate_dir: count = {count} > len = {len}"
+    );
+
+    let left = match dir {
+        Direction::Left => count,
+        Direction::Right => len - count,
+    };
+
+    stable_ptr_rotate(left, buf.add(left), len - left);
+}
 
-    loop {
-        // N.B. the below algorithms can fail if these cases are not checked
-        if (right == 0) || (left == 0) {
-            return;
-        }
+/// # Recommend
+///
+/// Returns the algorithm [`ptr_rotate`] uses for `T` and the given sides, without rotating
+/// anything, so it can be logged or asserted on before calling the matching `ptr_*` rotation:
+///
+/// * `min(left, right) <= 2` or `left == right` -- `RotationKind::Edge` (a shift or a single
+///   `ptr::swap_nonoverlapping`);
+/// * `left + right <= 24` or `size_of::<T>() > 4 * size_of::<usize>()` -- `RotationKind::Direct`;
+/// * otherwise -- `RotationKind::Contrev`.
+///
+/// See [`ptr_rotate`] for the benchmarks behind the thresholds.
+pub fn recommend<T>(left: usize, right: usize) -> RotationKind {
+    const DIRECT_MAX_LEN: usize = 24;
+    const DIRECT_MIN_SIZE: usize = 4 * core::mem::size_of::<usize>();
 
-        if (left + right < 24) || (std::mem::size_of::<T>() > std::mem::size_of::<[usize; 4]>()) {
-            // Algorithm 1
-            // Microbenchmarks indicate that the average performance for random shifts is better all
-            // the way until about `left + right == 32`, but the worst case performance breaks even
-            // around 16. 24 was chosen as middle ground. If the size of `T` is larger than 4
-            // `usize`s, this algorithm also outperforms other algorithms.
-            // SAFETY: callers must ensure `mid - left` is valid for reading and writing.
-            let x = unsafe { mid.sub(left) };
-            // beginning of first round
-            // SAFETY: see previous comment.
-            let mut tmp: T = unsafe { x.read() };
-            let mut i = right;
-            // `gcd` can be found before hand by calculating `gcd(left + right, right)`,
-            // but it is faster to do one loop which calculates the gcd as a side effect, then
-            // doing the rest of the chunk
-            let mut gcd = right;
-            // benchmarks reveal that it is faster to swap temporaries all the way through instead
-            // of reading one temporary once, copying backwards, and then writing that temporary at
-            // the very end. This is possibly due to the fact that swapping or replacing temporaries
-            // uses only one memory address in the loop instead of needing to manage two.
-            loop {
-                // [long-safety-expl]
-                // SAFETY: callers must ensure `[left, left+mid+right)` are all valid for reading and
-                // writing.
-                //
-                // - `i` start with `right` so `mid-left <= x+i = x+right = mid-left+right < mid+right`
-                // - `i <= left+right-1` is always true
-                //   - if `i < left`, `right` is added so `i < left+right` and on the next
-                //     iteration `left` is removed from `i` so it doesn't go further
-                //   - if `i >= left`, `left` is removed immediately and so it doesn't go further.
-                // - overflows cannot happen for `i` since the function's safety contract ask for
-                //   `mid+right-1 = x+left+right` to be valid for writing
-                // - underflows cannot happen because `i` must be bigger or equal to `left` for
-                //   a subtraction of `left` to happen.
-                //
-                // So `x+i` is valid for reading and writing if the caller respected the contract
-                tmp = unsafe { x.add(i).replace(tmp) };
-                // instead of incrementing `i` and then checking if it is outside the bounds, we
-                // check if `i` will go outside the bounds on the next increment. This prevents
-                // any wrapping of pointers or `usize`.
-                if i >= left {
-                    i -= left;
-                    if i == 0 {
-                        // end of first round
-                        // SAFETY: tmp has been read from a valid source and x is valid for writing
-                        // according to the caller.
-                        unsafe { x.write(tmp) };
-                        break;
-                    }
-                    // this conditional must be here if `left + right >= 15`
-                    if i < gcd {
-                        gcd = i;
-                    }
-                } else {
-                    i += right;
-                }
-            }
-            // finish the chunk with more rounds
-            for start in 1..gcd {
-                // SAFETY: `gcd` is at most equal to `right` so all values in `1..gcd` are valid for
-                // reading and writing as per the function's safety contract, see [long-safety-expl]
-                // above
-                tmp = unsafe { x.add(start).read() };
-                // [safety-expl-addition]
-                //
-                // Here `start < gcd` so `start < right` so `i < right+right`: `right` being the
-                // greatest common divisor of `(left+right, right)` means that `left = right` so
-                // `i < left+right` so `x+i = mid-left+i` is always valid for reading and writing
-                // according to the function's safety contract.
-                i = start + right;
-                loop {
-                    // SAFETY: see [long-safety-expl] and [safety-expl-addition]
-                    tmp = unsafe { x.add(i).replace(tmp) };
-                    if i >= left {
-                        i -= left;
-                        if i == start {
-                            // SAFETY: see [long-safety-expl] and [safety-expl-addition]
-                            unsafe { x.add(start).write(tmp) };
-                            break;
-                        }
-                    } else {
-                        i += right;
-                    }
-                }
-            }
-            return;
-        // `T` is not a zero-sized type, so it's okay to divide by its size.
-        } else if cmp::min(left, right) <= std::mem::size_of::<BufType>() / std::mem::size_of::<T>()
-        {
-            // Algorithm 2
-            // The `[T; 0]` here is to ensure this is appropriately aligned for T
-            let mut rawarray = MaybeUninit::<(BufType, [T; 0])>::uninit();
-            let buf = rawarray.as_mut_ptr() as *mut T;
-            // SAFETY: `mid-left <= mid-left+right < mid+right`
-            let dim = unsafe { mid.sub(left).add(right) };
-            if left <= right {
-                // SAFETY:
-                //
-                // 1) The `else if` condition about the sizes ensures `[mid-left; left]` will fit in
-                //    `buf` without overflow and `buf` was created just above and so cannot be
-                //    overlapped with any value of `[mid-left; left]`
-                // 2) [mid-left, mid+right) are all valid for reading and writing and we don't care
-                //    about overlaps here.
-                // 3) The `if` condition about `left <= right` ensures writing `left` elements to
-                //    `dim = mid-left+right` is valid because:
-                //    - `buf` is valid and `left` elements were written in it in 1)
-                //    - `dim+left = mid-left+right+left = mid+right` and we write `[dim, dim+left)`
-                unsafe {
-                    // 1)
-                    ptr::copy_nonoverlapping(mid.sub(left), buf, left);
-                    // 2)
-                    ptr::copy(mid, mid.sub(left), right);
-                    // 3)
-                    ptr::copy_nonoverlapping(buf, dim, left);
-                }
-            } else {
-                // SAFETY: same reasoning as above but with `left` and `right` reversed
-                unsafe {
-                    ptr::copy_nonoverlapping(mid, buf, right);
-                    ptr::copy(mid.sub(left), dim, left);
-                    ptr::copy_nonoverlapping(buf, mid.sub(left), right);
-                }
-            }
-            return;
-        } else if left >= right {
-            // Algorithm 3
-            //
-            //           left = 9         mid    right = 6
-            // [ 1  2  3  4  5  6  7  8  9,10 11 12 13 14 15]
-            //            └──────────────┴──┬──────────────┐
-            //    l = 3  mid                |    r = 6     |
-            // [ 1  2  3,10 11 12 13 14 15  4  5  6  7  8  9]
-            //
-            // There is an alternate way of swapping that involves finding where the last swap
-            // of this algorithm would be, and swapping using that last chunk instead of swapping
-            // adjacent chunks like this algorithm is doing, but this way is still faster.
-            loop {
-                // SAFETY:
-                // `left >= right` so `[mid-right, mid+right)` is valid for reading and writing
-                // Subtracting `right` from `mid` each turn is counterbalanced by the addition and
-                // check after it.
-                unsafe {
-                    ptr::swap_nonoverlapping(mid.sub(right), mid, right);
-                    mid = mid.sub(right);
-                }
-                left -= right;
-                if left < right {
-                    break;
-                }
-            }
-        } else {
-            // Algorithm 3, `left < right`
-            //
-            //  left = 3 mid                    right = 6
-            // [ 1  2  3,10 11 12 13 14 15 ,4  5  6  7  8  9]
-            //   └─────┴──┬─────┐
-            //   l = 3    |     | mid                r = 3
-            // [10 11 12  1  2  3,13 14 15  4  5  6, 7  8  9]
-            //   l = 3    └─────┴──┬─────┐ mid                r = 0
-            // [10 11 12 13 14 15  1  2  3 ,4  5  6  7  8  9],
-            loop {
-                // SAFETY: `[mid-left, mid+left)` is valid for reading and writing because
-                // `left < right` so `mid+left < mid+right`.
-                // Adding `left` to `mid` each turn is counterbalanced by the subtraction and check
-                // after it.
-                unsafe {
-                    ptr::swap_nonoverlapping(mid.sub(left), mid, left);
-                    mid = mid.add(left);
-                }
-                right -= left;
-                if right < left {
-                    break;
-                }
-            }
-        }
+    if left <= 2 || right <= 2 || left == right {
+        RotationKind::Edge
+    } else if left + right <= DIRECT_MAX_LEN || core::mem::size_of::<T>() > DIRECT_MIN_SIZE {
+        RotationKind::Direct
+    } else {
+        RotationKind::Contrev
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::test_utils::*;
     use crate::*;
 
-    fn div(s: usize, diff: usize) -> (usize, usize) {
-        assert!(s >= diff);
-        assert!(s % 2 == diff % 2);
+    #[test]
+    fn zst_rotate_noop() {
+        let rotations: [unsafe fn(usize, *mut (), usize); 33] = [
+            ptr_edge_rotate::<()>,
+            ptr_tiny_rotate::<()>,
+            ptr_rotate::<()>,
+            ptr_block_contrev_rotate::<()>,
+            ptr_reversal_rotate::<()>,
+            ptr_reversal_rotate_rec::<()>,
+            ptr_block_reversal_rotate::<()>,
+            ptr_comb_rotate::<()>,
+            ptr_piston_rotate_rec::<()>,
+            ptr_piston_rotate::<()>,
+            ptr_helix_rotate::<()>,
+            ptr_direct_rotate::<()>,
+            ptr_min_move_rotate::<()>,
+            ptr_juggling_rotate::<()>,
+            ptr_contrev_rotate::<()>,
+            ptr_contrev_rotate_v2::<()>,
+            ptr_harmony_rotate::<()>,
+            ptr_algo1_rotate::<()>,
+            stable_ptr_rotate::<()>,
+            ptr_griesmills_rotate_rec::<()>,
+            ptr_griesmills_rotate::<()>,
+            ptr_griesmills_rotate_opt::<()>,
+            ptr_grail_rotate::<()>,
+            ptr_swap_rotate::<()>,
+            ptr_drill_rotate::<()>,
+            ptr_aux_rotate_stack::<()>,
+            ptr_trinity_rotate_inplace::<()>,
+            |l, p, r| unsafe { ptr_aux_rotate::<()>(l, p, r, &mut [(); 100]) },
+            |l, p, r| unsafe { ptr_naive_aux_rotate::<()>(l, p, r, &mut [(); 100]) },
+            |l, p, r| unsafe { ptr_bridge_rotate::<()>(l, p, r, &mut [(); 100]) },
+            |l, p, r| unsafe { ptr_trinity_rotate::<()>(l, p, r, &mut [(); 100]) },
+            |l, p, r| unsafe { ptr_helix_rotate_aux::<()>(l, p, r, &mut [(); 100]) },
+            |l, p, r| unsafe { ptr_contrev_rotate_buffered::<()>(l, p, r, &mut [(); 100]) },
+        ];
+
+        for rotate in rotations {
+            for k in [0, 1, 2, 3, 33, 50, 67, 99, 100] {
+                let mut v = [(); 100];
+
+                // offsetting a pointer to a ZST is a no-op, so `mid == start`
+                unsafe { rotate(k, v.as_mut_ptr(), 100 - k) };
+
+                assert_eq!(v.len(), 100);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "ptr_direct_rotate: left + right")]
+    fn ptr_direct_rotate_too_long() {
+        type T = [u8; 1 << 10];
+
+        let half = max_rotation_len::<T>() / 2 + 1;
+        let mid = core::ptr::NonNull::<T>::dangling().as_ptr();
+
+        // panics before any pointer arithmetic
+        unsafe { ptr_direct_rotate::<T>(half, mid, half) };
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "ptr_contrev_rotate: left + right")]
+    fn ptr_contrev_rotate_too_long() {
+        type T = [u8; 1 << 10];
 
-        let r = s / 2 - diff / 2;
+        let mid = core::ptr::NonNull::<T>::dangling().as_ptr();
 
-        (s - r, r)
+        unsafe { ptr_contrev_rotate::<T>(usize::MAX, mid, 1) };
     }
 
-    fn seq(size: usize) -> Vec<usize> {
-        let mut v = vec![0; size];
-        for i in 0..size {
-            v[i] = i + 1;
+    #[test]
+    fn rotate_right_correct() {
+        type RotateF = unsafe fn(usize, *mut usize, usize);
+
+        let rotations: [(RotateF, RotateF); 3] = [
+            (ptr_reversal_rotate, ptr_reversal_rotate_right),
+            (ptr_piston_rotate, ptr_piston_rotate_right),
+            (ptr_contrev_rotate, ptr_contrev_rotate_right),
+        ];
+
+        for (rotate_left, rotate_right) in rotations {
+            sweep(|len, k| {
+                let mut v = seq(len);
+                let mut s = seq(len);
+
+                s.rotate_right(k);
+                unsafe { rotate_right(k, v.as_mut_ptr().add(len), len - k) };
+                assert_eq!(v, s, "len = {len}, k = {k}");
+
+                // back to the original order
+                unsafe { rotate_left(k, v.as_mut_ptr().add(k), len - k) };
+                assert_eq!(v, seq(len), "len = {len}, k = {k}");
+            });
         }
-        v
     }
 
-    fn prepare(size: usize, diff: usize) -> (Vec<usize>, (usize, *mut usize, usize)) {
-        let (l, r) = div(size, diff);
-        let mut v = seq(size);
+    #[test]
+    fn ptr_rotate_wrap_correct() {
+        sweep_slice(|v, start| unsafe { ptr_rotate_wrap(v.as_mut_ptr(), v.len(), start) });
+    }
 
-        unsafe {
-            let p = &v[..].as_mut_ptr().add(l);
-            (v, (l, p.clone(), r))
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "ptr_rotate_wrap: start = 4 > len = 3")]
+    fn ptr_rotate_wrap_out_of_bounds() {
+        let mut v = seq(3);
+
+        unsafe { ptr_rotate_wrap(v.as_mut_ptr(), 3, 4) };
+    }
+
+    #[test]
+    fn ptr_rotate_dir_correct() {
+        sweep(|len, count| {
+            let mut v = seq(len);
+            let mut s = seq(len);
+
+            unsafe { ptr_rotate_dir(count, v.as_mut_ptr(), len, Direction::Left) };
+            s.rotate_left(count);
+            assert_eq!(v, s, "left: len = {len}, count = {count}");
+
+            unsafe { ptr_rotate_dir(count, v.as_mut_ptr(), len, Direction::Right) };
+            assert_eq!(v, seq(len), "identity: len = {len}, count = {count}");
+
+            unsafe { ptr_rotate_dir(count, v.as_mut_ptr(), len, Direction::Right) };
+            s = seq(len);
+            s.rotate_right(count);
+            assert_eq!(v, s, "right: len = {len}, count = {count}");
+        });
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "ptr_rotate_dir: count = 4 > len = 3")]
+    fn ptr_rotate_dir_out_of_bounds() {
+        let mut v = seq(3);
+
+        unsafe { ptr_rotate_dir(4, v.as_mut_ptr(), 3, Direction::Right) };
+    }
+
+    #[test]
+    fn noop_rotate_correct() {
+        let rotations: [unsafe fn(usize, *mut usize, usize); 4] = [
+            ptr_piston_rotate,
+            ptr_helix_rotate,
+            ptr_griesmills_rotate,
+            ptr_drill_rotate,
+        ];
+
+        let len = 1_000_000;
+        let s = seq(len);
+        let mut v = seq(len);
+
+        for rotate in rotations {
+            // `k == 0` and `k == len`: returns before touching the slice
+            unsafe { rotate(0, v.as_mut_ptr(), len) };
+            unsafe { rotate(len, v.as_mut_ptr().add(len), 0) };
+
+            assert_eq!(v, s);
         }
     }
 
-    fn case(
-        rotate: unsafe fn(left: usize, mid: *mut usize, right: usize),
-        size: usize,
-        diff: usize,
-    ) {
-        let (vec, (l, p, r)) = prepare(size, diff);
+    #[test]
+    fn ptr_const_block_reversal_rotate_correct() {
+        fn check<const B: usize>() {
+            for blocks in [0, 1, 2, 3, 5, 8, 13, 40] {
+                for l in 0..=blocks {
+                    let (left, right) = (l * B, (blocks - l) * B);
+
+                    let mut v = seq(left + right);
+                    let mut s = seq(left + right);
+
+                    unsafe {
+                        ptr_const_block_reversal_rotate::<B, usize>(
+                            l,
+                            v.as_mut_ptr().add(left),
+                            blocks - l,
+                        );
+                        ptr_block_reversal_rotate(left, s.as_mut_ptr().add(left), right);
+                    }
 
-        let mut s = seq(size);
+                    assert_eq!(v, s, "B = {B}, blocks = {blocks}, left_blocks = {l}");
+                }
+            }
+        }
 
-        s.rotate_left(l);
-        unsafe { rotate(l, p, r) };
+        check::<1>();
+        check::<2>();
+        check::<3>();
+        check::<4>();
+        check::<7>();
+    }
 
-        assert_eq!(vec, s);
+    #[test]
+    fn rotate_rows_correct() {
+        for (rows, cols) in [(5, 3), (0, 3), (5, 0), (1, 4), (4, 1), (7, 2)] {
+            for k in 0..=2 * rows + 1 {
+                let mut v = seq(rows * cols);
+
+                let mut s: Vec<usize> = Vec::new();
+                for row in 0..rows {
+                    let row = (row + k) % rows;
+                    s.extend_from_slice(&seq(rows * cols)[row * cols..(row + 1) * cols]);
+                }
 
-        unsafe { rotate(r, p.sub(diff), l) };
+                unsafe { rotate_rows(v.as_mut_ptr(), rows, cols, k) };
 
-        s.rotate_right(l);
-        assert_eq!(vec, s);
+                assert_eq!(v, s, "rows = {rows}, cols = {cols}, k = {k}");
+            }
+        }
     }
 
-    fn test_correct(rotate_f: unsafe fn(left: usize, mid: *mut usize, right: usize)) {
-        // --empty--
-        case(rotate_f, 0, 0);
+    #[test]
+    fn rotate_cols_correct() {
+        fn transpose(v: &[usize], rows: usize, cols: usize) -> Vec<usize> {
+            (0..cols)
+                .flat_map(|col| (0..rows).map(move |row| v[row * cols + col]))
+                .collect()
+        }
 
-        // --empty--
-        case(rotate_f, 2, 0);
+        for (rows, cols) in [(4, 5), (0, 3), (5, 0), (1, 4), (4, 1), (3, 7)] {
+            for k in 0..=2 * cols + 1 {
+                let mut v = seq(rows * cols);
 
-        // 1  2  3  4  5  6 (7  8  9)10 11 12 13 14 15
-        case(rotate_f, 15, 3);
+                // transpose, rotate the rows, transpose back
+                let mut s = transpose(&v, rows, cols);
+                unsafe { rotate_rows(s.as_mut_ptr(), cols, rows, k) };
+                let s = transpose(&s, cols, rows);
 
-        // 1  2  3  4  5 (6  7  8  9 10)11 12 13 14 15
-        case(rotate_f, 15, 5);
+                unsafe { rotate_cols(v.as_mut_ptr(), rows, cols, k) };
 
-        // 1  2  3  4  5  6  7 (8) 9 10 11 12 13 14 15
-        case(rotate_f, 15, 1);
+                assert_eq!(v, s, "rows = {rows}, cols = {cols}, k = {k}");
+            }
+        }
+    }
 
-        // 1  2  3  4  5  6  7)(8  9 10 11 12 13 14
-        case(rotate_f, 14, 0);
+    #[test]
+    fn ptr_rotate_cfg_correct() {
+        fn check<const N: usize>(cfg: &RotateConfig) {
+            for len in (0..=80).chain([100, 1000]) {
+                for left in 0..=len {
+                    let mut v: Vec<[usize; N]> = (0..len).map(|i| [i; N]).collect();
+                    let mut s = v.clone();
+
+                    unsafe {
+                        ptr_rotate_cfg(left, v.as_mut_ptr().add(left), len - left, cfg);
+                        stable_ptr_rotate(left, s.as_mut_ptr().add(left), len - left);
+                    }
 
-        // 1  2  3  4  5 (6  7  8  9 10)11 12 13 14 15
-        case(rotate_f, 15, 5);
+                    assert_eq!(v, s, "{cfg:?}: N = {N}, len = {len}, left = {left}");
+                }
+            }
+        }
 
-        // 1  2  3  4 (5  6  7  8  9 10 11)12 13 14 15
-        case(rotate_f, 15, 7);
+        let default = RotateConfig::default();
 
-        // 1 (2  3  4  5  6  7  8  9 10 11 12 13 14)15
-        case(rotate_f, 15, 13);
+        check::<1>(&default);
+        check::<5>(&default);
 
-        //(1  2  3  4  5  6  7  8  9 10 11 12 13 14 15)
-        case(rotate_f, 15, 15);
+        // any thresholds give the same result, only the algorithms differ
+        for cfg in [
+            default.direct_max(0),
+            default.direct_max(usize::MAX),
+            default.aux_max_overlap(0),
+            default.aux_max_overlap(usize::MAX),
+            default.large_t_bytes(0),
+        ] {
+            check::<1>(&cfg);
+        }
+    }
 
-        //(1  2  3  4  5  6  7  8  9 10 11 12 13 14 15)
-        case(rotate_f, 100_000, 0);
+    #[test]
+    fn ptr_direct_rotate_no_double_drop() {
+        sweep_counted(|l, p, r| unsafe { ptr_direct_rotate(l, p, r) });
+    }
+
+    #[test]
+    fn fat_pointer_rotate_correct() {
+        use std::fmt::Debug;
+        use std::rc::Rc;
+
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct Tracked(usize, Rc<()>);
+
+        type Rotate = unsafe fn(usize, *mut Box<dyn Debug>, usize);
+
+        let rotations: [Rotate; 26] = [
+            ptr_edge_rotate,
+            ptr_tiny_rotate,
+            ptr_rotate,
+            ptr_block_contrev_rotate,
+            ptr_reversal_rotate,
+            ptr_reversal_rotate_rec,
+            ptr_block_reversal_rotate,
+            ptr_comb_rotate,
+            ptr_piston_rotate_rec,
+            ptr_piston_rotate,
+            ptr_helix_rotate,
+            ptr_direct_rotate,
+            ptr_min_move_rotate,
+            ptr_juggling_rotate,
+            ptr_contrev_rotate,
+            ptr_contrev_rotate_v2,
+            ptr_harmony_rotate,
+            ptr_algo1_rotate,
+            stable_ptr_rotate,
+            ptr_griesmills_rotate_rec,
+            ptr_griesmills_rotate,
+            ptr_griesmills_rotate_opt,
+            ptr_grail_rotate,
+            ptr_swap_rotate,
+            ptr_drill_rotate,
+            ptr_trinity_rotate_inplace,
+        ];
+
+        assert_eq!(
+            core::mem::size_of::<Box<dyn Debug>>(),
+            2 * core::mem::size_of::<usize>()
+        );
+
+        let len = 37;
+        let token = Rc::new(());
+
+        for rotate in rotations {
+            for k in [0, 1, 2, 3, 10, 18, 19, 27, 35, 36, 37] {
+                let mut v: Vec<Box<dyn Debug>> = (0..len)
+                    .map(|i| Box::new(Tracked(i, Rc::clone(&token))) as Box<dyn Debug>)
+                    .collect();
+
+                unsafe { rotate(k, v.as_mut_ptr().add(k), len - k) };
+
+                // nothing was dropped or duplicated
+                assert_eq!(Rc::strong_count(&token), len + 1);
+
+                let mut s: Vec<usize> = (0..len).collect();
+                s.rotate_left(k);
+
+                let expected: Vec<String> = s.iter().map(|i| format!("Tracked({i}, ())")).collect();
+                let actual: Vec<String> = v.iter().map(|b| format!("{b:?}")).collect();
+
+                assert_eq!(actual, expected, "k = {k}");
+
+                drop(v);
+
+                assert_eq!(Rc::strong_count(&token), 1);
+            }
+        }
     }
 
     #[test]
     // default (stable) rust rotate
-    fn ptr_rotate_correct() {
+    fn stable_ptr_rotate_correct() {
         test_correct(stable_ptr_rotate::<usize>);
     }
 
-    // #[test]
-    // fn ptr_harmony_rotate_correct() {
-    //     test_correct(ptr_harmony_rotate::<usize>);
-    // }
+    #[test]
+    fn ptr_contrev_rotate_v2_correct() {
+        test_correct(ptr_contrev_rotate_v2::<usize>);
+    }
+
+    #[test]
+    fn ptr_harmony_rotate_correct() {
+        test_correct(ptr_harmony_rotate::<usize>);
+    }
 
     #[test]
     fn ptr_edge_rotate_correct() {
-        let rotate_f = ptr_edge_rotate::<usize>;
+        let rotate_f = |l, p, r| unsafe { ptr_edge_rotate::<usize>(l, p, r) };
 
         // --empty--
         case(rotate_f, 0, 0);
@@ -1435,6 +3104,11 @@ mod tests {
         test_correct(ptr_block_reversal_rotate::<usize>);
     }
 
+    #[test]
+    fn ptr_comb_rotate_correct() {
+        test_correct(ptr_comb_rotate::<usize>);
+    }
+
     #[test]
     fn ptr_piston_rotate_rec_correct() {
         test_correct(ptr_piston_rotate_rec::<usize>);
@@ -1445,6 +3119,151 @@ mod tests {
         test_correct(ptr_piston_rotate::<usize>);
     }
 
+    #[test]
+    fn ptr_tiny_rotate_correct() {
+        test_correct(ptr_tiny_rotate::<usize>);
+
+        for len in 0..=8 {
+            for left in 0..=len {
+                let mut v = seq(len);
+                let mut s = seq(len);
+
+                s.rotate_left(left);
+                unsafe { ptr_tiny_rotate(left, v.as_mut_ptr().add(left), len - left) };
+
+                assert_eq!(v, s, "len = {len}, left = {left}");
+            }
+        }
+    }
+
+    #[test]
+    fn ptr_reversal_rotate_progress_correct() {
+        for len in 0..=20 {
+            for left in 0..=len {
+                let right = len - left;
+
+                let mut v = seq(len);
+                let mut s = seq(len);
+                let mut reported = Vec::new();
+
+                unsafe {
+                    ptr_reversal_rotate_progress(left, v.as_mut_ptr().add(left), right, &mut |n| {
+                        reported.push(n)
+                    });
+                    ptr_reversal_rotate(left, s.as_mut_ptr().add(left), right);
+                }
+
+                assert_eq!(v, s, "left = {left}, right = {right}");
+                assert_eq!(reported, [left, len, 2 * len]);
+            }
+        }
+    }
+
+    #[test]
+    fn rotate_bytes_raw_correct() {
+        for elem_size in [0, 1, 3, 8] {
+            for len in 0..=20 {
+                for left in 0..=len {
+                    // byte `j` of element `i` is `8 * i + j`, so all the bytes are distinct
+                    let elem = |i: usize| (0..elem_size).map(move |j| (i * 8 + j) as u8);
+
+                    let mut v: Vec<u8> = (0..len).flat_map(elem).collect();
+                    let s: Vec<u8> = (left..len).chain(0..left).flat_map(elem).collect();
+
+                    unsafe { rotate_bytes_raw(v.as_mut_ptr(), elem_size, left, len - left) };
+
+                    assert_eq!(v, s, "elem_size = {elem_size}, len = {len}, left = {left}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ptr_tiled_reversal_rotate_correct() {
+        test_correct(|l, p, r| unsafe { ptr_tiled_reversal_rotate::<usize>(l, p, r, 3) });
+
+        // `tile > len` for the last two
+        for tile in [0, 1, 2, 3, 7, 64, 10_000, reversal_tile::<usize>()] {
+            sweep_rotate(|l, p, r| unsafe { ptr_tiled_reversal_rotate(l, p, r, tile) });
+        }
+    }
+
+    #[test]
+    fn ptr_reverse_rotate_correct() {
+        for len in 0..=32 {
+            for left in 0..=len {
+                let right = len - left;
+
+                let mut v = seq(len);
+                let mut s = seq(len);
+
+                unsafe { ptr_reverse_rotate(left, v.as_mut_ptr().add(left), right) };
+
+                s.reverse();
+                s.rotate_left(left);
+
+                assert_eq!(v, s, "left = {left}, right = {right}");
+            }
+        }
+    }
+
+    #[test]
+    fn ptr_piston_rotate_residuals_correct() {
+        // pairs that leave the loop with small non-trivial residuals:
+        // (5, 3) -> (2, 3), (3, 5) -> (3, 2), (8, 5) -> (3, 2), (6, 9) -> (0, 3), ...
+        let cases = [
+            (2, 3),
+            (3, 2),
+            (5, 3),
+            (3, 5),
+            (8, 5),
+            (5, 8),
+            (13, 8),
+            (8, 13),
+            (6, 9),
+            (9, 6),
+            (12, 18),
+            (18, 12),
+            (7, 3),
+            (3, 7),
+        ];
+
+        for (left, right) in cases
+            .into_iter()
+            .chain((1..=64).flat_map(|l| (1..=64).map(move |r| (l, r))))
+        {
+            let mut v = seq(left + right);
+            let mut s = v.clone();
+
+            s.rotate_left(left);
+            unsafe { ptr_piston_rotate(left, v.as_mut_ptr().add(left), right) };
+
+            assert_eq!(v, s, "left = {left}, right = {right}");
+        }
+    }
+
+    #[test]
+    fn ptr_piston_rotate_near_equal_and_skewed() {
+        let mut cases = Vec::new();
+
+        for n in 1..=200 {
+            // `diff` 0 and 2
+            cases.extend([(n, n), (n, n + 2), (n + 2, n)]);
+            // extreme skew
+            cases.extend([(1, n), (n, 1), (2, n), (n, 2), (3, n), (n, 3)]);
+        }
+
+        for (left, right) in cases {
+            let mut v = seq(left + right);
+            let mut s = seq(left + right);
+
+            s.rotate_left(left);
+            unsafe { ptr_piston_rotate(left, v.as_mut_ptr().add(left), right) };
+
+            assert_eq!(v, s, "left = {left}, right = {right}");
+        }
+    }
+
     #[test]
     fn ptr_contrev_rotate_correct() {
         test_correct(ptr_contrev_rotate::<usize>);
@@ -1455,11 +3274,144 @@ mod tests {
         test_correct(ptr_block_contrev_rotate::<usize>);
     }
 
+    #[test]
+    fn ptr_block_contrev_rotate_degenerate_blocks() {
+        // `gcd(left, right) == min(left, right)`: the smaller side is a single block, so there
+        // are no four-cycles (`half_min == 0`)
+        let mut cases: Vec<(usize, usize)> = Vec::new();
+
+        for min in 3..=8 {
+            for blocks in 2..=7 {
+                cases.push((min * blocks, min));
+                cases.push((min, min * blocks));
+            }
+        }
+
+        // an odd number of blocks on one or both sides, so a middle block is left for the center
+        for b in 2..=6 {
+            for (l, r) in [(2, 3), (3, 5), (5, 7), (3, 7)] {
+                cases.push((l * b, r * b));
+                cases.push((r * b, l * b));
+            }
+        }
+
+        cases.extend([(6, 4), (4, 6), (4, 2), (2, 4), (9, 6), (6, 9)]);
+
+        for (left, right) in cases {
+            let mut v = seq(left + right);
+            let mut s = seq(left + right);
+
+            unsafe { ptr_block_contrev_rotate(left, v.as_mut_ptr().add(left), right) };
+            s.rotate_left(left);
+
+            assert_eq!(v, s, "left = {left}, right = {right}");
+        }
+    }
+
     #[test]
     fn ptr_direct_rotate_correct() {
         test_correct(ptr_direct_rotate::<usize>);
     }
 
+    #[test]
+    fn ptr_reversal_rotate_rec_correct() {
+        test_correct(ptr_reversal_rotate_rec::<usize>);
+    }
+
+    #[test]
+    fn ptr_reversal_rotate_rec_skewed() {
+        // ~330k steps: used to overflow the stack when every step was a recursive call.
+        let (left, right) = (3, 1_000_000);
+
+        let mut v = seq(left + right);
+        let mut s = v.clone();
+        s.rotate_left(left);
+
+        unsafe { ptr_reversal_rotate_rec(left, v.as_mut_ptr().add(left), right) };
+        assert_eq!(v, s);
+    }
+
+    #[test]
+    fn ptr_min_move_rotate_correct() {
+        test_correct(ptr_min_move_rotate::<usize>);
+    }
+
+    #[test]
+    fn ptr_direct_rotate_chunked_correct() {
+        test_correct(ptr_direct_rotate_chunked::<usize>);
+
+        for size in [16, 24, 48, 100, 1000] {
+            for l in (0..=size).filter(|l| binary_usize(*l, size - l) >= 8 || l % 13 == 0) {
+                let mut v = seq(size);
+                let mut s = seq(size);
+
+                unsafe { ptr_direct_rotate_chunked(l, v.as_mut_ptr().add(l), size - l) };
+                s.rotate_left(l);
+
+                assert_eq!(v, s, "size = {size}, left = {l}");
+            }
+        }
+    }
+
+    #[test]
+    fn ptr_rotate_copy_correct() {
+        test_correct(ptr_rotate_copy::<usize>);
+
+        for size in (0..=100).chain([1000]) {
+            for l in 0..=size {
+                let mut v = seq(size);
+                let mut s = seq(size);
+
+                unsafe { ptr_rotate_copy(l, v.as_mut_ptr().add(l), size - l) };
+                s.rotate_left(l);
+
+                assert_eq!(v, s, "size = {size}, left = {l}");
+            }
+        }
+    }
+
+    #[test]
+    fn ptr_juggling_rotate_correct() {
+        test_correct(ptr_juggling_rotate::<usize>);
+    }
+
+    #[test]
+    fn ptr_algo1_rotate_correct() {
+        test_correct(ptr_algo1_rotate::<usize>);
+    }
+
+    #[test]
+    fn recommend_correct() {
+        use RotationKind::*;
+
+        assert_eq!(recommend::<usize>(0, 100), Edge);
+        assert_eq!(recommend::<usize>(100, 2), Edge);
+        assert_eq!(recommend::<usize>(50, 50), Edge);
+        assert_eq!(recommend::<usize>(10, 14), Direct);
+        assert_eq!(recommend::<usize>(10, 15), Contrev);
+        assert_eq!(recommend::<u8>(3_000, 7_000), Contrev);
+        assert_eq!(recommend::<[usize; 4]>(3_000, 7_000), Contrev);
+        assert_eq!(recommend::<[usize; 5]>(3_000, 7_000), Direct);
+        assert_eq!(recommend::<()>(3_000, 7_000), Contrev);
+    }
+
+    #[test]
+    fn ptr_rotate_correct() {
+        test_correct(ptr_rotate::<usize>);
+
+        for len in [0, 10, 24, 25, 100] {
+            for l in 0..=len {
+                let mut v: Vec<[usize; 5]> = (0..len).map(|i| [i; 5]).collect();
+                let mut s = v.clone();
+
+                unsafe { ptr_rotate(l, v.as_mut_ptr().add(l), len - l) };
+                s.rotate_left(l);
+
+                assert_eq!(v, s, "len = {len}, left = {l}");
+            }
+        }
+    }
+
     #[test]
     fn ptr_helix_rotate_correct() {
         test_correct(ptr_helix_rotate::<usize>);
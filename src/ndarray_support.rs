@@ -0,0 +1,105 @@
+/*
+Copyright (C) 2023 Valentin Vasilev (3volny@gmail.com).
+*/
+
+//! Rotations of [`ndarray`] views. Available with the `ndarray` feature.
+
+use ndarray::ArrayViewMut1;
+
+/// # Rotate `ndarray` view left
+///
+/// Rotates the one-dimensional view in-place such that its first `k` elements move to the end,
+/// like [`rotate_left`](crate::rotate_left) does for slices.
+///
+/// If the view is contiguous in standard order, its slice is rotated with
+/// [`rotate_left`](crate::rotate_left). Otherwise (strided or reversed views) it is rotated
+/// logically, as a *Triple reversal rotation* of swaps by index.
+///
+/// ## Panics
+///
+/// This function will panic if `k` is greater than the length of the view. Note that `k ==
+/// a.len()` does _not_ panic and is a no-op rotation.
+///
+/// ## Example
+///
+/// ```text
+///                 k = 5
+/// [ 1  2  3  4  5* 6  7  8  9]  // rotate_array1
+/// [ 6  7  8  9: 1  2  3  4  5]
+/// ```
+pub fn rotate_array1<T>(a: &mut ArrayViewMut1<T>, k: usize) {
+    let len = a.len();
+
+    assert!(k <= len, "rotate_array1: k = {k} > len = {len}");
+
+    if let Some(s) = a.as_slice_mut() {
+        crate::rotate_left(s, k);
+        return;
+    }
+
+    #[inline(always)]
+    fn reverse<T>(a: &mut ArrayViewMut1<T>, mut i: usize, mut j: usize) {
+        while i + 1 < j {
+            a.swap(i, j - 1);
+            i += 1;
+            j -= 1;
+        }
+    }
+
+    reverse(a, 0, k);
+    reverse(a, k, len);
+    reverse(a, 0, len);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    use ndarray::{s, Array1};
+
+    #[test]
+    fn rotate_array1_contiguous() {
+        for len in [0, 1, 2, 3, 10, 15, 100] {
+            for k in 0..=len {
+                let mut a: Array1<usize> = (1..=len).collect();
+                let mut s: Vec<usize> = (1..=len).collect();
+
+                rotate_array1(&mut a.view_mut(), k);
+                s.rotate_left(k);
+
+                assert_eq!(a.to_vec(), s, "len = {len}, k = {k}");
+            }
+        }
+    }
+
+    #[test]
+    fn rotate_array1_strided() {
+        for len in [0, 1, 2, 3, 10, 15, 100] {
+            for k in 0..=len {
+                let mut a: Array1<usize> = (0..3 * len).collect();
+
+                // every third element, and the same view reversed
+                for step in [3, -3] {
+                    let mut view = a.slice_mut(s![..;step]);
+                    let mut s = view.to_vec();
+
+                    // views of up to one element are always contiguous
+                    assert_eq!(view.as_slice_mut().is_none(), len > 1);
+
+                    rotate_array1(&mut view, k);
+                    s.rotate_left(k);
+
+                    assert_eq!(view.to_vec(), s, "len = {len}, k = {k}, step = {step}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "rotate_array1: k = 4 > len = 3")]
+    fn rotate_array1_out_of_range() {
+        let mut a = Array1::from(vec![1, 2, 3]);
+
+        rotate_array1(&mut a.view_mut(), 4);
+    }
+}
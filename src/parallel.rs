@@ -0,0 +1,133 @@
+/*
+Copyright (C) 2023 Valentin Vasilev (3volny@gmail.com).
+*/
+
+//! Multithreaded rotations for huge arrays. Available with the `parallel` feature.
+
+use crate::{is_zst, ptr_edge_rotate, ptr_reversal_rotate};
+use core::slice;
+
+/// Number of elements below which [`ptr_reversal_rotate_parallel`] falls back to the serial
+/// [`ptr_reversal_rotate`]: spawning tasks costs more than reversing a short range.
+pub const PARALLEL_THRESHOLD: usize = 1 << 16;
+
+/// Elements swapped by one task of the parallel reversal.
+const CHUNK: usize = 1 << 14;
+
+/// # Triple reversal rotation (Parallel)
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// Same as [`ptr_reversal_rotate`], but the left and the right sides are reversed at the same time
+/// with `rayon::join`, and every reversal swaps its pairs by chunks in parallel. Ranges shorter
+/// than [`PARALLEL_THRESHOLD`] are rotated serially.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+pub unsafe fn ptr_reversal_rotate_parallel<T: Send>(left: usize, mid: *mut T, right: usize) {
+    ptr_reversal_rotate_parallel_thresh(left, mid, right, PARALLEL_THRESHOLD);
+}
+
+/// # Triple reversal rotation (Parallel, with threshold)
+///
+/// Rotates the range `[mid-left, mid+right)` such that the element at `mid` becomes the first
+/// element. Equivalently, rotates the range `left` elements to the left or `right` elements to the
+/// right.
+///
+/// ## Algorithm
+///
+/// Same as [`ptr_reversal_rotate_parallel`], but ranges shorter than `threshold` are rotated with
+/// the serial [`ptr_reversal_rotate`].
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+pub unsafe fn ptr_reversal_rotate_parallel_thresh<T: Send>(
+    left: usize,
+    mid: *mut T,
+    right: usize,
+    threshold: usize,
+) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    if left + right < threshold {
+        ptr_reversal_rotate(left, mid, right);
+        return;
+    }
+
+    if right <= 2 || left <= 2 || left == right {
+        ptr_edge_rotate(left, mid, right);
+        return;
+    }
+
+    let start = mid.sub(left);
+
+    let l = slice::from_raw_parts_mut(start, left);
+    let r = slice::from_raw_parts_mut(mid, right);
+
+    rayon::join(|| par_reverse(l), || par_reverse(r));
+
+    par_reverse(slice::from_raw_parts_mut(start, left + right));
+}
+
+/// Reverses `s`, swapping its pairs by chunks in parallel.
+fn par_reverse<T: Send>(s: &mut [T]) {
+    use rayon::prelude::*;
+
+    let half = s.len() / 2;
+    let (lo, hi) = s.split_at_mut(s.len() - half);
+
+    lo[..half]
+        .par_chunks_mut(CHUNK)
+        .zip(hi.par_rchunks_mut(CHUNK))
+        .for_each(|(x, y)| {
+            for (a, b) in x.iter_mut().zip(y.iter_mut().rev()) {
+                core::mem::swap(a, b);
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::seq;
+    use crate::*;
+
+    #[test]
+    fn ptr_reversal_rotate_parallel_correct() {
+        for size in [0, 1, 2, 3, 10, 15, 100, 1000] {
+            for l in 0..=size {
+                let mut v = seq(size);
+                let mut s = seq(size);
+
+                unsafe {
+                    ptr_reversal_rotate_parallel_thresh(l, v.as_mut_ptr().add(l), size - l, 0)
+                };
+                s.rotate_left(l);
+
+                assert_eq!(v, s, "size = {size}, left = {l}");
+            }
+        }
+    }
+
+    #[test]
+    fn ptr_reversal_rotate_parallel_large_correct() {
+        let size = 3 * PARALLEL_THRESHOLD + 7;
+
+        for l in [1, 3, 12_345, size / 2, size - 40_000, size - 3] {
+            let mut v = seq(size);
+            let mut s = seq(size);
+
+            unsafe { ptr_reversal_rotate_parallel(l, v.as_mut_ptr().add(l), size - l) };
+            s.rotate_left(l);
+
+            assert_eq!(v, s, "size = {size}, left = {l}");
+        }
+    }
+}
@@ -0,0 +1,189 @@
+/*
+Copyright (C) 2023 Valentin Vasilev (3volny@gmail.com).
+*/
+
+//! Property tests: every rotation against `<[T]>::rotate_left` on random lengths and offsets.
+
+use crate::*;
+
+use core::fmt::Debug;
+use proptest::prelude::*;
+
+type Rotate<T> = unsafe fn(left: usize, mid: *mut T, right: usize);
+
+/// All the rotations, buffered ones get a buffer that fits the whole range.
+fn rotations<T: Copy + Default>() -> Vec<(&'static str, Rotate<T>)> {
+    vec![
+        ("ptr_edge_rotate", ptr_edge_rotate::<T>),
+        ("ptr_tiny_rotate", ptr_tiny_rotate::<T>),
+        ("ptr_rotate", ptr_rotate::<T>),
+        ("ptr_rotate_copy", ptr_rotate_copy::<T>),
+        ("ptr_block_contrev_rotate", ptr_block_contrev_rotate::<T>),
+        ("ptr_reversal_rotate", ptr_reversal_rotate::<T>),
+        ("ptr_reversal_rotate_rec", ptr_reversal_rotate_rec::<T>),
+        ("ptr_block_reversal_rotate", ptr_block_reversal_rotate::<T>),
+        ("ptr_comb_rotate", ptr_comb_rotate::<T>),
+        ("ptr_piston_rotate_rec", ptr_piston_rotate_rec::<T>),
+        ("ptr_piston_rotate", ptr_piston_rotate::<T>),
+        ("ptr_helix_rotate", ptr_helix_rotate::<T>),
+        ("ptr_direct_rotate", ptr_direct_rotate::<T>),
+        ("ptr_direct_rotate_chunked", ptr_direct_rotate_chunked::<T>),
+        ("ptr_min_move_rotate", ptr_min_move_rotate::<T>),
+        ("ptr_juggling_rotate", ptr_juggling_rotate::<T>),
+        ("ptr_contrev_rotate", ptr_contrev_rotate::<T>),
+        ("ptr_contrev_rotate_v2", ptr_contrev_rotate_v2::<T>),
+        ("ptr_harmony_rotate", ptr_harmony_rotate::<T>),
+        ("ptr_algo1_rotate", ptr_algo1_rotate::<T>),
+        ("stable_ptr_rotate", stable_ptr_rotate::<T>),
+        ("ptr_griesmills_rotate_rec", ptr_griesmills_rotate_rec::<T>),
+        ("ptr_griesmills_rotate", ptr_griesmills_rotate::<T>),
+        ("ptr_griesmills_rotate_opt", ptr_griesmills_rotate_opt::<T>),
+        ("ptr_grail_rotate", ptr_grail_rotate::<T>),
+        ("ptr_swap_rotate", ptr_swap_rotate::<T>),
+        ("ptr_drill_rotate", ptr_drill_rotate::<T>),
+        ("ptr_aux_rotate_stack", ptr_aux_rotate_stack::<T>),
+        ("ptr_small_shift_rotate", ptr_small_shift_rotate::<T>),
+        ("ptr_rotate_auto_buffer", ptr_rotate_auto_buffer::<T>),
+        (
+            "ptr_trinity_rotate_inplace",
+            ptr_trinity_rotate_inplace::<T>,
+        ),
+        ("ptr_aux_rotate", |l, p, r| unsafe {
+            ptr_aux_rotate::<T>(l, p, r, &mut vec![T::default(); l + r])
+        }),
+        ("ptr_naive_aux_rotate", |l, p, r| unsafe {
+            ptr_naive_aux_rotate::<T>(l, p, r, &mut vec![T::default(); l + r])
+        }),
+        ("ptr_bridge_rotate", |l, p, r| unsafe {
+            ptr_bridge_rotate::<T>(l, p, r, &mut vec![T::default(); l + r])
+        }),
+        ("ptr_trinity_rotate", |l, p, r| unsafe {
+            ptr_trinity_rotate::<T>(l, p, r, &mut vec![T::default(); (l + r) / 4])
+        }),
+        ("ptr_helix_rotate_aux", |l, p, r| unsafe {
+            ptr_helix_rotate_aux::<T>(l, p, r, &mut [T::default(); 2])
+        }),
+        ("ptr_contrev_rotate_buffered", |l, p, r| unsafe {
+            ptr_contrev_rotate_buffered::<T>(l, p, r, &mut [T::default(); 32])
+        }),
+    ]
+}
+
+fn check<T: Copy + Default + PartialEq + Debug>(v: &[T], k: usize) {
+    let mut s = v.to_vec();
+    s.rotate_left(k);
+
+    let mut w = v.to_vec();
+    safe_reversal_rotate_left(&mut w, k);
+
+    assert_eq!(
+        w,
+        s,
+        "safe_reversal_rotate_left: len = {}, k = {k}",
+        v.len()
+    );
+
+    for (name, rotate) in rotations::<T>() {
+        let mut w = v.to_vec();
+
+        unsafe { rotate(k, w.as_mut_ptr().add(k), v.len() - k) };
+
+        assert_eq!(w, s, "{name}: len = {}, k = {k}", v.len());
+    }
+}
+
+#[test]
+fn equal_halves_correct() {
+    for half in [0, 1, 2, 3, 7, 50, 500] {
+        let v: Vec<usize> = (0..2 * half).collect();
+
+        check(&v, half);
+    }
+}
+
+/// Runs every rotation on a copy of `v` and asserts the results are identical to each other,
+/// with the names of the first two that diverge.
+fn differential<T: Copy + Default + PartialEq + Debug>(v: &[T], k: usize) {
+    let mut results = rotations::<T>().into_iter().map(|(name, rotate)| {
+        let mut w = v.to_vec();
+
+        unsafe { rotate(k, w.as_mut_ptr().add(k), v.len() - k) };
+
+        (name, w)
+    });
+
+    let (first, expected) = results.next().unwrap();
+
+    for (name, w) in results {
+        assert_eq!(w, expected, "{name} != {first}: len = {}, k = {k}", v.len());
+    }
+
+    let mut s = v.to_vec();
+    s.rotate_left(k);
+
+    assert_eq!(expected, s, "{first}: len = {}, k = {k}", v.len());
+}
+
+#[test]
+fn rotations_agree() {
+    for len in 1..=64 {
+        let v: Vec<u64> = (0..len as u64)
+            .map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+            .collect();
+
+        for k in 0..=len {
+            differential(&v, k);
+        }
+
+        let v: Vec<[u8; 7]> = (0..len)
+            .map(|i| core::array::from_fn(|j| (i * 7 + j) as u8))
+            .collect();
+
+        for k in 0..=len {
+            differential(&v, k);
+        }
+    }
+}
+
+fn len_and_k(max_len: usize) -> impl Strategy<Value = (usize, usize)> {
+    (0..=max_len).prop_flat_map(|len| (Just(len), 0..=len))
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn rotations_u32((len, k) in len_and_k(3000)) {
+        let v: Vec<u32> = (0..len as u32).collect();
+
+        check(&v, k);
+    }
+
+    #[test]
+    fn rotations_usize_1((len, k) in len_and_k(1000)) {
+        let v: Vec<[usize; 1]> = (0..len).map(|i| [i; 1]).collect();
+
+        check(&v, k);
+    }
+
+    #[test]
+    fn rotations_usize_2((len, k) in len_and_k(1000)) {
+        let v: Vec<[usize; 2]> = (0..len).map(|i| [i; 2]).collect();
+
+        check(&v, k);
+    }
+
+    #[test]
+    fn rotations_usize_5((len, k) in len_and_k(1000)) {
+        let v: Vec<[usize; 5]> = (0..len).map(|i| [i; 5]).collect();
+
+        check(&v, k);
+    }
+
+    #[test]
+    fn rotations_usize_10((len, k) in len_and_k(1000)) {
+        let v: Vec<[usize; 10]> = (0..len).map(|i| [i; 10]).collect();
+
+        check(&v, k);
+    }
+}
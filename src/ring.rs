@@ -0,0 +1,212 @@
+/*
+Copyright (C) 2023 Valentin Vasilev (3volny@gmail.com).
+*/
+
+//! Rotations of ring buffers: lazy rotations of a slice, where many small rotations are
+//! accumulated in a logical head and applied at once, and rotations of a `VecDeque`.
+
+use crate::*;
+
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+
+/// # Ring rotator
+///
+/// Borrows a fixed backing store and rotates it lazily: [`advance`](RingRotator::advance) only
+/// moves the logical head, and [`materialize`](RingRotator::materialize) performs the
+/// accumulated rotation with [`stable_ptr_rotate`] when contiguous order is needed. Many small
+/// rotations cost one rotation.
+///
+/// The logical sequence starts at `data[head]` and wraps around to `data[head - 1]`.
+///
+/// ## Example
+///
+/// ```
+/// use rust_rotations::RingRotator;
+///
+/// let mut v = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+/// let mut ring = RingRotator::new(&mut v);
+///
+/// ring.advance(2);
+/// ring.advance(3);
+/// assert_eq!(ring.as_slices(), (&[6, 7, 8, 9][..], &[1, 2, 3, 4, 5][..]));
+///
+/// ring.materialize();
+/// assert_eq!(v, [6, 7, 8, 9, 1, 2, 3, 4, 5]);
+/// ```
+pub struct RingRotator<'a, T> {
+    data: &'a mut [T],
+    head: usize,
+}
+
+impl<'a, T> RingRotator<'a, T> {
+    /// Creates a rotator over `data` with the head at `0`.
+    pub fn new(data: &'a mut [T]) -> Self {
+        Self { data, head: 0 }
+    }
+
+    /// Index in the backing store of the first element of the logical sequence.
+    pub fn head(&self) -> usize {
+        self.head
+    }
+
+    /// Rotates the logical sequence left by `k` elements, without moving any data. `k` wraps
+    /// around the length, so it may be greater than it.
+    pub fn advance(&mut self, k: usize) {
+        if self.data.is_empty() {
+            return;
+        }
+
+        let len = self.data.len();
+        let s = k % len;
+
+        // `head + s` could overflow for a slice of ZSTs longer than `usize::MAX / 2`
+        self.head = if s >= len - self.head {
+            self.head - (len - s)
+        } else {
+            self.head + s
+        };
+    }
+
+    /// Returns the logical sequence as two slices of the backing store, `data[head..]` and
+    /// `data[..head]`, without moving any data.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        split_rotated(self.data, self.head)
+    }
+
+    /// Performs the accumulated rotation, so the backing store is in logical order and the head
+    /// is `0` again.
+    pub fn materialize(&mut self) {
+        let (left, right) = (self.head, self.data.len() - self.head);
+
+        if left == 0 {
+            return;
+        }
+
+        if !is_zst::<T>() {
+            // SAFETY: `[mid - left, mid + right)` is exactly `data`.
+            unsafe { stable_ptr_rotate(left, self.data.as_mut_ptr().add(left), right) };
+        }
+
+        self.head = 0;
+    }
+}
+
+/// # Rotate deque left
+///
+/// Rotates the deque such that its first `k % d.len()` elements move to the back, as `k`
+/// `pop_front` + `push_back` pairs would. `VecDeque` is already a ring buffer, so this is
+/// `VecDeque::rotate_left`, which only moves the smaller side and the head; `k` wraps around the
+/// length instead of panicking. An empty deque is left untouched.
+///
+/// Available with the `alloc` feature.
+///
+/// ## Example
+///
+/// ```text
+///                 k = 5
+/// [ 1  2  3  4  5* 6  7  8  9]  // rotate_deque_left
+/// [ 6  7  8  9: 1  2  3  4  5]
+/// ```
+#[cfg(feature = "alloc")]
+pub fn rotate_deque_left<T>(d: &mut VecDeque<T>, k: usize) {
+    if d.is_empty() {
+        return;
+    }
+
+    let k = k % d.len();
+
+    d.rotate_left(k);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::seq;
+    use crate::*;
+    use core::ptr::NonNull;
+
+    #[test]
+    fn ring_rotator_correct() {
+        for len in [0, 1, 2, 3, 10, 15, 100] {
+            for step in [0, 1, 2, 7, 15, 101] {
+                let mut v = seq(len);
+                let mut s = seq(len);
+
+                let mut ring = RingRotator::new(&mut v);
+
+                for _ in 0..5 {
+                    ring.advance(step);
+
+                    if len > 0 {
+                        s.rotate_left(step % len);
+                    }
+
+                    let (front, back) = ring.as_slices();
+                    assert_eq!([front, back].concat(), s, "len = {len}, step = {step}");
+                }
+
+                ring.materialize();
+                assert_eq!(ring.head(), 0);
+                assert_eq!(v, s, "len = {len}, step = {step}");
+            }
+        }
+    }
+
+    #[test]
+    fn rotate_deque_left_correct() {
+        use std::collections::VecDeque;
+
+        for len in [0, 1, 2, 3, 10, 15, 100] {
+            for k in (0..=2 * len).chain([1000]) {
+                // start from a wrapped-around layout
+                let mut d: VecDeque<usize> = VecDeque::with_capacity(len);
+                for i in (1..=len).rev() {
+                    d.push_front(i);
+                }
+
+                let mut s = d.clone();
+                for _ in 0..k {
+                    if let Some(x) = s.pop_front() {
+                        s.push_back(x);
+                    }
+                }
+
+                rotate_deque_left(&mut d, k);
+
+                assert_eq!(d, s, "len = {len}, k = {k}");
+            }
+        }
+    }
+
+    #[test]
+    fn ring_rotator_materialize_twice() {
+        let mut v = seq(10);
+        let mut ring = RingRotator::new(&mut v);
+
+        ring.advance(3);
+        ring.materialize();
+        ring.advance(4);
+        ring.materialize();
+
+        let mut s = seq(10);
+        s.rotate_left(7);
+
+        assert_eq!(v, s);
+    }
+
+    #[test]
+    fn ring_rotator_zst() {
+        let len = usize::MAX / 2 + 10;
+        let v: &mut [()] =
+            unsafe { core::slice::from_raw_parts_mut(NonNull::dangling().as_ptr(), len) };
+        let mut ring = RingRotator::new(v);
+
+        // `head + k % len` would overflow here
+        ring.advance(len - 5);
+        ring.advance(len - 5);
+        assert_eq!(ring.head(), len - 10);
+
+        ring.materialize();
+        assert_eq!(ring.head(), 0);
+    }
+}
@@ -0,0 +1,1411 @@
+/*
+Copyright (C) 2023 Valentin Vasilev (3volny@gmail.com).
+*/
+
+/*
+Permission is hereby granted, free of charge, to any person obtaining
+a copy of this software and associated documentation files (the
+"Software"), to deal in the Software without restriction, including
+without limitation the rights to use, copy, modify, merge, publish,
+distribute, sublicense, and/or sell copies of the Software, and to
+permit persons to whom the Software is furnished to do so, subject to
+the following conditions:
+
+The above copyright notice and this permission notice shall be
+included in all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY
+CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,
+TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE
+SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+use crate::*;
+
+use core::cmp;
+use core::mem::MaybeUninit;
+use core::ops::Range;
+use core::ptr;
+
+/// Splits `s` at `k` into the `(left, mid, right)` triple expected by the `ptr_*` rotations.
+///
+/// ## Panics
+///
+/// Panics if `k > s.len()`.
+#[inline(always)]
+pub(crate) fn split_at_ptr<T>(s: &mut [T], k: usize, name: &str) -> (usize, *mut T, usize) {
+    assert!(k <= s.len(), "{name}: k = {k} > len = {}", s.len());
+
+    // SAFETY: `k <= s.len()`, so `mid` is in-bounds or one past the end.
+    let mid = unsafe { s.as_mut_ptr().add(k) };
+
+    (k, mid, s.len() - k)
+}
+
+/// # Rotate left
+///
+/// Rotates the slice in-place such that the first `k` elements of the slice move to the end
+/// while the last `s.len() - k` elements move to the front. After calling `rotate_left`, the
+/// element previously at index `k` will become the first element in the slice.
+///
+/// Safe counterpart of `stable_ptr_rotate` with the same semantics as `<[T]>::rotate_left`.
+///
+/// ## Panics
+///
+/// This function will panic if `k` is greater than the length of the slice. Note that `k ==
+/// s.len()` does _not_ panic and is a no-op rotation.
+///
+/// ## Example
+///
+/// ```text
+///                 k = 5
+/// [ 1  2  3  4  5* 6  7  8  9]  // rotate_left
+/// [ 6  7  8  9: 1  2  3  4  5]
+/// ```
+pub fn rotate_left<T>(s: &mut [T], k: usize) {
+    let (left, mid, right) = split_at_ptr(s, k, "rotate_left");
+
+    if is_zst::<T>() {
+        return;
+    }
+
+    // SAFETY: `[mid - left, mid + right)` is exactly `s`, which is valid for reading and writing.
+    unsafe { stable_ptr_rotate(left, mid, right) };
+}
+
+/// # Rotate right
+///
+/// Rotates the slice in-place such that the first `s.len() - k` elements of the slice move to
+/// the end while the last `k` elements move to the front. After calling `rotate_right`, the
+/// element previously at index `s.len() - k` will become the first element in the slice.
+///
+/// Safe counterpart of `stable_ptr_rotate` with the same semantics as `<[T]>::rotate_right`.
+///
+/// ## Panics
+///
+/// This function will panic if `k` is greater than the length of the slice. Note that `k ==
+/// s.len()` does _not_ panic and is a no-op rotation.
+///
+/// ## Example
+///
+/// ```text
+///                    k = 4
+/// [ 1  2  3  4  5* 6  7  8  9]  // rotate_right
+/// [ 6  7  8  9: 1  2  3  4  5]
+/// ```
+pub fn rotate_right<T>(s: &mut [T], k: usize) {
+    assert!(k <= s.len(), "rotate_right: k = {k} > len = {}", s.len());
+
+    let (left, mid, right) = split_at_ptr(s, s.len() - k, "rotate_right");
+
+    if is_zst::<T>() {
+        return;
+    }
+
+    // SAFETY: `[mid - left, mid + right)` is exactly `s`, which is valid for reading and writing.
+    unsafe { stable_ptr_rotate(left, mid, right) };
+}
+
+/// # Rotate error
+///
+/// Error returned by [`checked_rotate_left`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RotateError {
+    /// The rotation count `k` is greater than the length `len` of the slice.
+    OutOfRange {
+        /// Requested rotation count.
+        k: usize,
+        /// Length of the slice.
+        len: usize,
+    },
+}
+
+impl core::fmt::Display for RotateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RotateError::OutOfRange { k, len } => write!(f, "k = {k} > len = {len}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RotateError {}
+
+/// # Std rotate left
+///
+/// Calls `<[T]>::rotate_left(k)` from the standard library. It is the baseline for the rotations
+/// of this crate, and this shim lets the benchmarks call it like the other slice rotations (see the
+/// `VsStd` benchmark).
+///
+/// ## Panics
+///
+/// This function will panic if `k` is greater than the length of the slice.
+pub fn std_rotate_left<T>(s: &mut [T], k: usize) {
+    s.rotate_left(k);
+}
+
+/// # Checked rotate left
+///
+/// Same as [`rotate_left`], but returns `Err(RotateError::OutOfRange { k, len })` instead of
+/// panicking if `k` is greater than the length of the slice. The slice is left untouched then.
+pub fn checked_rotate_left<T>(s: &mut [T], k: usize) -> Result<(), RotateError> {
+    if k > s.len() {
+        return Err(RotateError::OutOfRange { k, len: s.len() });
+    }
+
+    rotate_left(s, k);
+
+    Ok(())
+}
+
+/// # Wrapping rotate left
+///
+/// Same as [`rotate_left`], but `k` is reduced modulo the length of the slice first, so it never
+/// panics. Rotating an empty slice is a no-op.
+///
+/// ## Example
+///
+/// ```text
+///                 k = 14 % 9 = 5
+/// [ 1  2  3  4  5* 6  7  8  9]  // wrapping_rotate_left
+/// [ 6  7  8  9: 1  2  3  4  5]
+/// ```
+pub fn wrapping_rotate_left<T>(s: &mut [T], k: usize) {
+    if s.is_empty() {
+        return;
+    }
+
+    let len = s.len();
+
+    rotate_left(s, k % len);
+}
+
+/// # Signed rotation
+///
+/// Rotates the slice in-place left by `amount` elements if it is positive, or right by `-amount`
+/// elements if it is negative. `amount` is reduced modulo the length of the slice first (with
+/// `rem_euclid`), so it never panics. Rotating an empty slice is a no-op.
+///
+/// ## Example
+///
+/// ```text
+///               amount = -4
+/// [ 1  2  3  4  5* 6  7  8  9]  // rotate_signed
+/// [ 6  7  8  9: 1  2  3  4  5]
+/// ```
+pub fn rotate_signed<T>(s: &mut [T], amount: isize) {
+    if s.is_empty() || is_zst::<T>() {
+        return;
+    }
+
+    // `s.len() <= isize::MAX` for non-ZSTs
+    let k = amount.rem_euclid(s.len() as isize) as usize;
+
+    rotate_left(s, k);
+}
+
+/// # Split rotated
+///
+/// Returns `(&s[k..], &s[..k])`: the two pieces of the slice that, read in order, are the slice
+/// rotated left by `k`, without moving any data. Concatenated they are equal to `s` after
+/// [`rotate_left`]`(s, k)`.
+///
+/// ## Panics
+///
+/// This function will panic if `k` is greater than the length of the slice. Note that `k ==
+/// s.len()` does _not_ panic and returns `(&[], s)`.
+///
+/// ## Example
+///
+/// ```text
+///                 k = 5
+/// [ 1  2  3  4  5* 6  7  8  9]  // split_rotated
+///  ([ 6  7  8  9], [ 1  2  3  4  5])
+/// ```
+pub fn split_rotated<T>(s: &[T], k: usize) -> (&[T], &[T]) {
+    assert!(k <= s.len(), "split_rotated: k = {k} > len = {}", s.len());
+
+    let (back, front) = s.split_at(k);
+
+    (front, back)
+}
+
+/// # Split rotated (mutable)
+///
+/// Same as [`split_rotated`], but returns mutable slices, with `split_at_mut`.
+///
+/// ## Panics
+///
+/// This function will panic if `k` is greater than the length of the slice.
+pub fn split_rotated_mut<T>(s: &mut [T], k: usize) -> (&mut [T], &mut [T]) {
+    assert!(
+        k <= s.len(),
+        "split_rotated_mut: k = {k} > len = {}",
+        s.len()
+    );
+
+    let (back, front) = s.split_at_mut(k);
+
+    (front, back)
+}
+
+/// # Rotated iterator
+///
+/// Iterates over the slice in the order [`wrapping_rotate_left`] would leave it in, without
+/// moving any data: yields `s[k % len..]` and then `s[..k % len]`. Iterating over an empty slice
+/// yields nothing.
+///
+/// ## Example
+///
+/// ```text
+///                 k = 5
+/// [ 1  2  3  4  5* 6  7  8  9]  // iter_rotated
+///   6  7  8  9  1  2  3  4  5
+/// ```
+pub fn iter_rotated<T>(s: &[T], k: usize) -> impl Iterator<Item = &T> {
+    let k = if s.is_empty() { 0 } else { k % s.len() };
+    let (front, back) = split_rotated(s, k);
+
+    front.iter().chain(back)
+}
+
+/// # Rotated mutable iterator
+///
+/// Same as [`iter_rotated`], but yields mutable references.
+pub fn iter_rotated_mut<T>(s: &mut [T], k: usize) -> impl Iterator<Item = &mut T> {
+    let k = if s.is_empty() { 0 } else { k % s.len() };
+    let (front, back) = split_rotated_mut(s, k);
+
+    front.iter_mut().chain(back)
+}
+
+/// # Rotate array left
+///
+/// Rotates the owned array left by `k % N` elements and returns it: the element previously at
+/// index `k % N` becomes the first one. Rotating an empty array is a no-op.
+///
+/// ## Example
+///
+/// ```text
+///                 k = 5
+/// [ 1  2  3  4  5* 6  7  8  9]  // rotate_array_left
+/// [ 6  7  8  9: 1  2  3  4  5]
+/// ```
+pub fn rotate_array_left<T, const N: usize>(mut arr: [T; N], k: usize) -> [T; N] {
+    if N == 0 {
+        return arr;
+    }
+
+    let (left, mid, right) = split_at_ptr(&mut arr, k % N, "rotate_array_left");
+
+    // SAFETY: `[mid - left, mid + right)` is exactly `arr`, which is owned here.
+    unsafe { stable_ptr_rotate(left, mid, right) };
+
+    arr
+}
+
+/// # Out-of-place rotation
+///
+/// Writes `src` rotated left by `k` elements into `dst`, leaving `src` untouched: `dst` gets
+/// `src[k..]` followed by `src[..k]`. Two `clone_from_slice` calls, so existing elements of `dst`
+/// are assigned to (and dropped), not overwritten. For a read-only source, instead of cloning it
+/// and rotating the clone in-place.
+///
+/// ## Panics
+///
+/// This function will panic if `k` is greater than the length of `src`, or if `src` and `dst`
+/// have different lengths.
+///
+/// ## Example
+///
+/// ```text
+///                 k = 5
+/// [ 1  2  3  4  5* 6  7  8  9]  // src
+///   └───────────┘  └────────┘
+///   ┌────────┐  ┌───────────┐
+/// [ 6  7  8  9: 1  2  3  4  5]  // dst
+/// ```
+pub fn rotate_into<T: Clone>(src: &[T], k: usize, dst: &mut [T]) {
+    let len = check_rotate_into(src, k, dst, "rotate_into");
+
+    dst[..len - k].clone_from_slice(&src[k..]);
+    dst[len - k..].clone_from_slice(&src[..k]);
+}
+
+/// # Out-of-place rotation (`Copy`)
+///
+/// Same as [`rotate_into`] for `T: Copy`: both halves are copied with `copy_from_slice`, i.e.
+/// `ptr::copy_nonoverlapping` (`memcpy`).
+///
+/// ## Panics
+///
+/// This function will panic if `k` is greater than the length of `src`, or if `src` and `dst`
+/// have different lengths.
+pub fn rotate_into_copy<T: Copy>(src: &[T], k: usize, dst: &mut [T]) {
+    let len = check_rotate_into(src, k, dst, "rotate_into_copy");
+
+    dst[..len - k].copy_from_slice(&src[k..]);
+    dst[len - k..].copy_from_slice(&src[..k]);
+}
+
+/// Checks the arguments of the out-of-place rotations and returns the length.
+#[inline(always)]
+fn check_rotate_into<T>(src: &[T], k: usize, dst: &[T], name: &str) -> usize {
+    let len = src.len();
+
+    assert!(k <= len, "{name}: k = {k} > len = {len}");
+    assert!(
+        dst.len() == len,
+        "{name}: dst len = {} != src len = {len}",
+        dst.len()
+    );
+
+    len
+}
+
+/// # Rotate bytes left
+///
+/// Same as [`rotate_left`] for byte slices, but bypasses the generic size heuristics:
+///
+/// 1. `k == s.len() - k` -- a single [`swap_halves`];
+/// 2. `min(k, s.len() - k) <= 4096` -- *Auxiliary rotation* through a stack buffer: the smaller
+///    side is copied out, the larger one is moved with `memmove` (`ptr::copy`), and the smaller
+///    side is copied back;
+/// 3. otherwise -- in-place *Triple reversal rotation* with `<[u8]>::reverse`, which LLVM
+///    vectorizes.
+///
+/// ## Panics
+///
+/// This function will panic if `k` is greater than the length of the slice.
+///
+/// ## Example
+///
+/// ```text
+///                 k = 5
+/// [ 1  2  3  4  5* 6  7  8  9]  // rotate_bytes_left
+/// [ 6  7  8  9: 1  2  3  4  5]
+/// ```
+pub fn rotate_bytes_left(s: &mut [u8], k: usize) {
+    const STACK: usize = 4096;
+
+    let (left, mid, right) = split_at_ptr(s, k, "rotate_bytes_left");
+
+    if left == 0 || right == 0 {
+        return;
+    }
+
+    if left == right {
+        // SAFETY: the two halves of `s` don't overlap.
+        unsafe { swap_halves(mid.sub(left), mid, left) };
+    } else if cmp::min(left, right) <= STACK {
+        let mut buf = MaybeUninit::<[u8; STACK]>::uninit();
+        let buf = buf.as_mut_ptr().cast::<u8>();
+
+        // SAFETY: `[mid - left, mid + right)` is exactly `s`, the smaller side fits into `buf`,
+        // and `ptr::copy` allows the overlap of the larger side with its destination.
+        unsafe {
+            let start = mid.sub(left);
+
+            if left <= right {
+                ptr::copy_nonoverlapping(start, buf, left);
+                ptr::copy(mid, start, right);
+                ptr::copy_nonoverlapping(buf, start.add(right), left);
+            } else {
+                ptr::copy_nonoverlapping(mid, buf, right);
+                ptr::copy(start, start.add(right), left);
+                ptr::copy_nonoverlapping(buf, start, right);
+            }
+        }
+    } else {
+        s[..k].reverse();
+        s[k..].reverse();
+        s.reverse();
+    }
+}
+
+/// # Rotate left (tracking)
+///
+/// Same as [`rotate_left`], but also returns the new index of the element that was at `index`,
+/// i.e. `(index + len - k) % len`. Handy to keep an external cursor (a playhead, a selection) in
+/// sync with the rotated buffer.
+///
+/// ## Panics
+///
+/// This function will panic if `k` is greater than the length of the slice, or if `index` is out
+/// of bounds.
+///
+/// ## Example
+///
+/// ```text
+///                 k = 5, index = 1
+/// [ 1  2  3  4  5* 6  7  8  9]  // rotate_left_tracking
+///      ^
+/// [ 6  7  8  9: 1  2  3  4  5]  // returns 5
+///                  ^
+/// ```
+pub fn rotate_left_tracking<T>(s: &mut [T], k: usize, index: usize) -> usize {
+    let len = s.len();
+
+    assert!(
+        index < len,
+        "rotate_left_tracking: index = {index} >= len = {len}"
+    );
+
+    rotate_left(s, k);
+
+    if index >= k {
+        index - k
+    } else {
+        index + (len - k)
+    }
+}
+
+/// # Safe reversal rotation
+///
+/// Same as [`rotate_left`], implemented as a *Triple reversal rotation* without any `unsafe`:
+///
+/// ```text
+/// s[..k].reverse();
+/// s[k..].reverse();
+/// s.reverse();
+/// ```
+///
+/// Pays for the bounds checks, but is a simple reference implementation, e.g. for the property
+/// tests.
+///
+/// ## Panics
+///
+/// This function will panic if `k` is greater than the length of the slice.
+pub fn safe_reversal_rotate_left<T>(s: &mut [T], k: usize) {
+    assert!(
+        k <= s.len(),
+        "safe_reversal_rotate_left: k = {k} > len = {}",
+        s.len()
+    );
+
+    s[..k].reverse();
+    s[k..].reverse();
+    s.reverse();
+}
+
+/// # Drop-safe rotate left
+///
+/// Same as [`rotate_left`], but for types with drop glue (`core::mem::needs_drop::<T>()`) uses
+/// [`ptr_contrev_rotate`], which needs no buffer and holds at most a few elements outside of
+/// the slice at any time.
+///
+/// ## Panic safety
+///
+/// All the rotations of the crate move elements bitwise (`ptr::copy*`, `ptr::swap*`,
+/// `read`/`write`) and never call user code (`Clone`, `Drop`, comparisons) in between, so none of
+/// them can panic or drop a value mid-way, and each element ends up in the slice exactly once.
+/// They differ in how many elements are held outside of the slice while the rotation is in
+/// progress, which would matter for a variant that calls user code:
+///
+/// ```text
+///  rotations                                       | held mid-way
+/// -------------------------------------------------+--------------------------------------
+///  reversal, griesmills, piston, helix, comb,      | the elements being swapped
+///  drill, swap, block reversal                     |
+///  contrev, direct, juggling, min_move, algo1,     | one to four elements
+///  edge                                            |
+///  aux, bridge, trinity, stable_ptr_rotate         | a buffer of `min(left, right)`
+///                                                  | elements (or of the bridge)
+/// ```
+///
+/// ## Panics
+///
+/// This function will panic if `k` is greater than the length of the slice. Note that `k ==
+/// s.len()` does _not_ panic and is a no-op rotation.
+pub fn rotate_drop_safe<T>(s: &mut [T], k: usize) {
+    let (left, mid, right) = split_at_ptr(s, k, "rotate_drop_safe");
+
+    if is_zst::<T>() {
+        return;
+    }
+
+    // SAFETY: `[mid - left, mid + right)` is exactly `s`, which is valid for reading and writing.
+    unsafe {
+        if core::mem::needs_drop::<T>() {
+            ptr_contrev_rotate(left, mid, right);
+        } else {
+            stable_ptr_rotate(left, mid, right);
+        }
+    }
+}
+
+/// # Rotate range left
+///
+/// Rotates the sub-slice `s[range]` in-place such that its first `k` elements move to its end,
+/// leaving the rest of `s` untouched. Same as `s[range].rotate_left(k)`, without the need to
+/// reslice and recompute offsets.
+///
+/// ## Panics
+///
+/// This function will panic if `range.start > range.end`, if `range.end` is greater than the
+/// length of the slice, or if `k` is greater than `range.len()`. Note that an empty `range` and
+/// `k == range.len()` do _not_ panic and are no-op rotations.
+///
+/// ## Example
+///
+/// ```text
+///           range = 2..8, k = 2
+/// [ 1  2 :3  4* 5  6  7  8: 9]  // rotate_range_left
+/// [ 1  2 :5  6  7  8* 3  4: 9]
+/// ```
+pub fn rotate_range_left<T>(s: &mut [T], range: Range<usize>, k: usize) {
+    let Range { start, end } = range;
+
+    assert!(
+        start <= end,
+        "rotate_range_left: range start = {start} > range end = {end}"
+    );
+    assert!(
+        end <= s.len(),
+        "rotate_range_left: range end = {end} > len = {}",
+        s.len()
+    );
+    assert!(
+        k <= end - start,
+        "rotate_range_left: k = {k} > range len = {}",
+        end - start
+    );
+
+    if is_zst::<T>() {
+        return;
+    }
+
+    // SAFETY: `start + k <= end <= s.len()`, so `[mid - k, mid + end - start - k)` is exactly
+    // `s[start..end]`, which is valid for reading and writing.
+    unsafe {
+        let mid = s.as_mut_ptr().add(start + k);
+
+        stable_ptr_rotate(k, mid, end - start - k);
+    }
+}
+
+/// # Rotate outer left
+///
+/// Rotates a jagged sequence of rows such that its first `k` rows move to the end, like
+/// [`rotate_left`] does. Only the `Vec` headers (pointer, capacity, length) are moved: every row
+/// keeps its allocation and no inner element is copied, so the cost depends on `rows.len()`, not
+/// on the total number of elements. The same holds for any slice of pointer-like elements, e.g.
+/// `&mut [&mut [T]]` with [`rotate_left`].
+///
+/// Available with the `alloc` feature.
+///
+/// ## Panics
+///
+/// This function will panic if `k` is greater than the number of rows. Note that `k ==
+/// rows.len()` does _not_ panic and is a no-op rotation.
+///
+/// ## Example
+///
+/// ```text
+///                    k = 2
+/// [[1  2] [3] [4  5  6]* [] [7]]  // rotate_outer_left
+/// [[4  5  6] [] [7]: [1  2] [3]]
+/// ```
+#[cfg(feature = "alloc")]
+pub fn rotate_outer_left<T>(rows: &mut [alloc::vec::Vec<T>], k: usize) {
+    let (left, mid, right) = split_at_ptr(rows, k, "rotate_outer_left");
+
+    // SAFETY: `[mid - left, mid + right)` is exactly `rows`, which is valid for reading and
+    // writing.
+    unsafe { stable_ptr_rotate(left, mid, right) };
+}
+
+/// # Split rotation
+///
+/// Rotates the logical concatenation `a ++ b` of two slices (e.g. the two halves of a gap
+/// buffer) in-place such that its first `k` elements move to its end. After the call `a` holds
+/// the first `a.len()` elements of the rotated sequence and `b` holds the rest.
+///
+/// ## Algorithm
+///
+/// If one of the slices is empty, only the other one is rotated with [`rotate_left`].
+/// Otherwise *Gries-Mills* block swaps are made over the logical sequence, each block swap being
+/// split into `ptr::swap_nonoverlapping` runs at the boundary between `a` and `b`. Slices that
+/// are adjacent in memory are rotated the same way: they are separate borrows, so neither can be
+/// used to reach the other.
+///
+/// ## Panics
+///
+/// This function will panic if `k` is greater than `a.len() + b.len()`.
+///
+/// ## Example
+///
+/// ```text
+///           k = 4
+/// [ 1  2  3] [ 4* 5  6  7]  // rotate_split
+/// [ 5  6  7] [ 1  2  3  4]
+/// ```
+pub fn rotate_split<T>(a: &mut [T], b: &mut [T], k: usize) {
+    let len = a.len() + b.len();
+
+    assert!(k <= len, "rotate_split: k = {k} > len = {len}");
+
+    if is_zst::<T>() || k == 0 || k == len {
+        return;
+    }
+
+    if b.is_empty() {
+        rotate_left(a, k);
+        return;
+    }
+
+    if a.is_empty() {
+        rotate_left(b, k);
+        return;
+    }
+
+    let (mut start, mut left, mut right) = (0, k, len - k);
+
+    while left > 0 && right > 0 {
+        if left <= right {
+            // SAFETY: `start + left + left <= len` and the ranges don't overlap.
+            unsafe { swap_split(a, b, start, start + left, left) };
+            start += left;
+            right -= left;
+        } else {
+            // SAFETY: `start + left + right <= len` and the ranges don't overlap.
+            unsafe { swap_split(a, b, start + left - right, start + left, right) };
+            left -= right;
+        }
+    }
+}
+
+/// Swaps logical ranges `[x, x + count)` and `[y, y + count)` of `a ++ b`.
+/// `x + count <= y` and `y + count <= a.len() + b.len()` must hold.
+unsafe fn swap_split<T>(a: &mut [T], b: &mut [T], mut x: usize, mut y: usize, mut count: usize) {
+    let at = |a: &mut [T], b: &mut [T], i: usize| -> (*mut T, usize) {
+        if i < a.len() {
+            (a.as_mut_ptr().add(i), a.len() - i)
+        } else {
+            (b.as_mut_ptr().add(i - a.len()), a.len() + b.len() - i)
+        }
+    };
+
+    while count > 0 {
+        let (px, rx) = at(a, b, x);
+        let (py, ry) = at(a, b, y);
+
+        let run = cmp::min(count, cmp::min(rx, ry));
+
+        ptr::swap_nonoverlapping(px, py, run);
+
+        x += run;
+        y += run;
+        count -= run;
+    }
+}
+
+/// # Rotation
+///
+/// Safe, algorithm-specific rotations. Every method rotates the slice in-place such that the
+/// element at index `k` becomes the first one, exactly as `<[T]>::rotate_left(k)` does, but
+/// using the named `ptr_*` algorithm.
+///
+/// Implemented for `[T]`, so it is also available on `Vec<T>` and arrays through deref.
+///
+/// ## Panics
+///
+/// Every method panics if `k` is greater than the length of the slice. Buffered methods
+/// also panic if the `buffer` is shorter than the algorithm requires.
+///
+/// ## Buffers
+///
+/// Buffered algorithms bitwise copy elements into the `buffer` and leave those copies there,
+/// so they require `T: Copy`.
+pub trait Rotation<T> {
+    /// Rotates with `ptr_direct_rotate`.
+    fn direct_rotate_left(&mut self, k: usize);
+
+    /// Rotates with `ptr_reversal_rotate`.
+    fn reversal_rotate_left(&mut self, k: usize);
+
+    /// Rotates with `ptr_block_reversal_rotate`.
+    fn block_reversal_rotate_left(&mut self, k: usize);
+
+    /// Rotates with `ptr_contrev_rotate`.
+    fn contrev_rotate_left(&mut self, k: usize);
+
+    /// Rotates with `ptr_block_contrev_rotate`.
+    fn block_contrev_rotate_left(&mut self, k: usize);
+
+    /// Rotates with `ptr_piston_rotate`.
+    fn piston_rotate_left(&mut self, k: usize);
+
+    /// Rotates with `ptr_helix_rotate`.
+    fn helix_rotate_left(&mut self, k: usize);
+
+    /// Rotates with `ptr_griesmills_rotate`.
+    fn griesmills_rotate_left(&mut self, k: usize);
+
+    /// Rotates with `ptr_drill_rotate`.
+    fn drill_rotate_left(&mut self, k: usize);
+
+    /// Rotates with `ptr_aux_rotate`.
+    ///
+    /// The `buffer` length must be at least `min(k, len - k)`.
+    fn aux_rotate_left(&mut self, k: usize, buffer: &mut [T])
+    where
+        T: Copy;
+
+    /// Rotates with `ptr_bridge_rotate`.
+    ///
+    /// The `buffer` length must be at least `min(|len - 2k|, k, len - k)`.
+    fn bridge_rotate_left(&mut self, k: usize, buffer: &mut [T])
+    where
+        T: Copy;
+
+    /// Rotates with `ptr_trinity_rotate`. Any `buffer` length is accepted.
+    fn trinity_rotate_left(&mut self, k: usize, buffer: &mut [T])
+    where
+        T: Copy;
+}
+
+macro_rules! rotate_left_with {
+    ($name:ident, $rotate:ident) => {
+        fn $name(&mut self, k: usize) {
+            let (left, mid, right) = split_at_ptr(self, k, stringify!($name));
+
+            // SAFETY: `[mid - left, mid + right)` is exactly `self`.
+            unsafe { $rotate(left, mid, right) };
+        }
+    };
+}
+
+impl<T> Rotation<T> for [T] {
+    rotate_left_with!(direct_rotate_left, ptr_direct_rotate);
+    rotate_left_with!(reversal_rotate_left, ptr_reversal_rotate);
+    rotate_left_with!(block_reversal_rotate_left, ptr_block_reversal_rotate);
+    rotate_left_with!(contrev_rotate_left, ptr_contrev_rotate);
+    rotate_left_with!(block_contrev_rotate_left, ptr_block_contrev_rotate);
+    rotate_left_with!(piston_rotate_left, ptr_piston_rotate);
+    rotate_left_with!(helix_rotate_left, ptr_helix_rotate);
+    rotate_left_with!(griesmills_rotate_left, ptr_griesmills_rotate);
+    rotate_left_with!(drill_rotate_left, ptr_drill_rotate);
+
+    fn aux_rotate_left(&mut self, k: usize, buffer: &mut [T])
+    where
+        T: Copy,
+    {
+        let (left, mid, right) = split_at_ptr(self, k, "aux_rotate_left");
+
+        assert!(
+            buffer.len() >= left.min(right),
+            "aux_rotate_left: buffer is too small"
+        );
+
+        // SAFETY: `[mid - left, mid + right)` is exactly `self` and the buffer fits the
+        // smallest side.
+        unsafe { ptr_aux_rotate(left, mid, right, buffer) };
+    }
+
+    fn bridge_rotate_left(&mut self, k: usize, buffer: &mut [T])
+    where
+        T: Copy,
+    {
+        let (left, mid, right) = split_at_ptr(self, k, "bridge_rotate_left");
+
+        assert!(
+            buffer.len() >= left.abs_diff(right).min(left).min(right),
+            "bridge_rotate_left: buffer is too small"
+        );
+
+        // SAFETY: `[mid - left, mid + right)` is exactly `self` and the buffer fits either the
+        // bridge or the smallest side, whichever is used.
+        unsafe { ptr_bridge_rotate(left, mid, right, buffer) };
+    }
+
+    fn trinity_rotate_left(&mut self, k: usize, buffer: &mut [T])
+    where
+        T: Copy,
+    {
+        let (left, mid, right) = split_at_ptr(self, k, "trinity_rotate_left");
+
+        // SAFETY: `[mid - left, mid + right)` is exactly `self`; `ptr_trinity_rotate` only
+        // uses the buffer when the moved part fits into it.
+        unsafe { ptr_trinity_rotate(left, mid, right, buffer) };
+    }
+}
+
+/// # Rotation kind
+///
+/// In-place rotation algorithm to be used by `rotate_with`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RotationKind {
+    /// `ptr_direct_rotate`.
+    Direct,
+    /// `ptr_reversal_rotate`.
+    Reversal,
+    /// `ptr_block_reversal_rotate`.
+    BlockReversal,
+    /// `ptr_piston_rotate`.
+    Piston,
+    /// `ptr_helix_rotate`.
+    Helix,
+    /// `ptr_contrev_rotate`.
+    Contrev,
+    /// `ptr_block_contrev_rotate`.
+    BlockContrev,
+    /// `ptr_griesmills_rotate`.
+    GriesMills,
+    /// `ptr_drill_rotate`.
+    Drill,
+    /// `ptr_edge_rotate`.
+    Edge,
+    /// `stable_ptr_rotate`.
+    Stable,
+}
+
+impl RotationKind {
+    /// All the variants, in declaration order.
+    pub const ALL: [RotationKind; 11] = [
+        RotationKind::Direct,
+        RotationKind::Reversal,
+        RotationKind::BlockReversal,
+        RotationKind::Piston,
+        RotationKind::Helix,
+        RotationKind::Contrev,
+        RotationKind::BlockContrev,
+        RotationKind::GriesMills,
+        RotationKind::Drill,
+        RotationKind::Edge,
+        RotationKind::Stable,
+    ];
+}
+
+/// # Buffered rotation kind
+///
+/// Rotation algorithm that needs an auxiliary buffer, to be used by `rotate_with_buffer`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BufRotationKind {
+    /// `ptr_aux_rotate`.
+    Aux,
+    /// `ptr_bridge_rotate`.
+    Bridge,
+    /// `ptr_trinity_rotate`.
+    Trinity,
+}
+
+impl BufRotationKind {
+    /// All the variants, in declaration order.
+    pub const ALL: [BufRotationKind; 3] = [
+        BufRotationKind::Aux,
+        BufRotationKind::Bridge,
+        BufRotationKind::Trinity,
+    ];
+}
+
+/// # Rotate with
+///
+/// Rotates the slice in-place such that the element at index `k` becomes the first one, using the
+/// algorithm selected by `kind`. Same semantics as `<[T]>::rotate_left`.
+///
+/// ## Panics
+///
+/// This function will panic if `k` is greater than the length of the slice.
+pub fn rotate_with<T>(kind: RotationKind, s: &mut [T], k: usize) {
+    use RotationKind::*;
+
+    let rotate: unsafe fn(usize, *mut T, usize) = match kind {
+        Direct => ptr_direct_rotate,
+        Reversal => ptr_reversal_rotate,
+        BlockReversal => ptr_block_reversal_rotate,
+        Piston => ptr_piston_rotate,
+        Helix => ptr_helix_rotate,
+        Contrev => ptr_contrev_rotate,
+        BlockContrev => ptr_block_contrev_rotate,
+        GriesMills => ptr_griesmills_rotate,
+        Drill => ptr_drill_rotate,
+        Edge => ptr_edge_rotate,
+        Stable => stable_ptr_rotate,
+    };
+
+    let (left, mid, right) = split_at_ptr(s, k, "rotate_with");
+
+    // SAFETY: `[mid - left, mid + right)` is exactly `s`, which is valid for reading and writing.
+    unsafe { rotate(left, mid, right) };
+}
+
+/// # Rotate with buffer
+///
+/// Rotates the slice in-place such that the element at index `k` becomes the first one, using the
+/// buffered algorithm selected by `kind`. Same semantics as `<[T]>::rotate_left`.
+///
+/// ## Panics
+///
+/// This function will panic if `k` is greater than the length of the slice or if the `buffer` is
+/// too small for the selected algorithm (see `Rotation`).
+pub fn rotate_with_buffer<T: Copy>(kind: BufRotationKind, s: &mut [T], k: usize, buffer: &mut [T]) {
+    match kind {
+        BufRotationKind::Aux => s.aux_rotate_left(k, buffer),
+        BufRotationKind::Bridge => s.bridge_rotate_left(k, buffer),
+        BufRotationKind::Trinity => s.trinity_rotate_left(k, buffer),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::slice::*;
+    use crate::test_utils::*;
+
+    #[test]
+    fn rotate_drop_safe_correct() {
+        use std::cell::Cell;
+
+        sweep(|len, k| {
+            if k % (1 + len / 40) != 0 {
+                return;
+            }
+
+            let live = Cell::new(0);
+            let mut v = counted(len, &live);
+
+            let mut s: Vec<usize> = (0..len).collect();
+            s.rotate_left(k);
+
+            rotate_drop_safe(&mut v, k);
+            assert_eq!(live.get(), len as isize, "no drop: len = {len}, k = {k}");
+            assert!(v.iter().map(|c| c.0).eq(s), "len = {len}, k = {k}");
+
+            drop(v);
+            assert_eq!(live.get(), 0, "no double drop: len = {len}, k = {k}");
+        });
+
+        // types without drop glue take the `stable_ptr_rotate` path
+        sweep_slice(rotate_drop_safe);
+    }
+
+    #[test]
+    fn rotate_left_correct() {
+        sweep_slice(rotate_left);
+    }
+
+    #[test]
+    fn rotate_right_correct() {
+        sweep_slice(|v, k| rotate_right(v, v.len() - k));
+    }
+
+    #[test]
+    fn rotation_trait_correct() {
+        let methods: [fn(&mut [usize], usize); 9] = [
+            |s, k| s.direct_rotate_left(k),
+            |s, k| s.reversal_rotate_left(k),
+            |s, k| s.block_reversal_rotate_left(k),
+            |s, k| s.contrev_rotate_left(k),
+            |s, k| s.block_contrev_rotate_left(k),
+            |s, k| s.piston_rotate_left(k),
+            |s, k| s.helix_rotate_left(k),
+            |s, k| s.griesmills_rotate_left(k),
+            |s, k| s.drill_rotate_left(k),
+        ];
+
+        let mut buffer = vec![0; 1000];
+
+        for rotate in methods {
+            sweep_slice(rotate);
+        }
+
+        sweep_slice(|v, k| v.aux_rotate_left(k, &mut buffer));
+        sweep_slice(|v, k| v.bridge_rotate_left(k, &mut buffer));
+        sweep_slice(|v, k| v.trinity_rotate_left(k, &mut buffer[..v.len() / 8]));
+    }
+
+    #[test]
+    fn rotate_with_correct() {
+        let mut buffer = vec![0; 1000];
+
+        for kind in RotationKind::ALL {
+            sweep_slice(|v, k| rotate_with(kind, v, k));
+        }
+
+        for kind in BufRotationKind::ALL {
+            sweep_slice(|v, k| rotate_with_buffer(kind, v, k, &mut buffer));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn rotation_trait_out_of_bounds() {
+        seq(15).contrev_rotate_left(16);
+    }
+
+    #[test]
+    #[should_panic]
+    fn aux_rotate_left_small_buffer() {
+        seq(15).aux_rotate_left(7, &mut [0; 6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rotate_left_out_of_bounds() {
+        rotate_left(&mut seq(15), 16);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rotate_right_out_of_bounds() {
+        rotate_right(&mut seq(15), 16);
+    }
+
+    #[test]
+    fn std_rotate_left_correct() {
+        for len in [0, 1, 2, 3, 10, 15, 100] {
+            for k in 0..=len {
+                let mut v = seq(len);
+                let mut s = seq(len);
+
+                std_rotate_left(&mut v, k);
+                rotate_left(&mut s, k);
+
+                assert_eq!(v, s, "len = {len}, k = {k}");
+            }
+        }
+    }
+
+    #[test]
+    fn checked_rotate_left_correct() {
+        sweep_slice(|v, k| assert_eq!(checked_rotate_left(v, k), Ok(())));
+
+        for len in (0..=40).chain([100, 1000]) {
+            let mut v = seq(len);
+
+            for k in [len + 1, 2 * len + 1, usize::MAX] {
+                assert_eq!(
+                    checked_rotate_left(&mut v, k),
+                    Err(RotateError::OutOfRange { k, len })
+                );
+                assert_eq!(v, seq(len));
+            }
+        }
+
+        assert_eq!(
+            RotateError::OutOfRange { k: 5, len: 3 }.to_string(),
+            "k = 5 > len = 3"
+        );
+    }
+
+    #[test]
+    fn wrapping_rotate_left_correct() {
+        wrapping_rotate_left::<usize>(&mut [], 7);
+
+        for len in (1..=40).chain([100, 1000]) {
+            for k in (0..=3 * len).chain([usize::MAX]) {
+                let mut v = seq(len);
+                let mut s = seq(len);
+
+                wrapping_rotate_left(&mut v, k);
+                s.rotate_left(k % len);
+
+                assert_eq!(v, s, "len = {len}, k = {k}");
+            }
+        }
+    }
+
+    #[test]
+    fn rotate_signed_correct() {
+        for len in [0, 1, 2, 3, 10, 15, 100] {
+            let len_i = len as isize;
+
+            for amount in (-2 * len_i - 1..=2 * len_i + 1).chain([isize::MIN, isize::MAX]) {
+                let mut v = seq(len);
+                let mut s = seq(len);
+
+                rotate_signed(&mut v, amount);
+
+                if len > 0 {
+                    let k = amount.unsigned_abs() % len;
+
+                    if amount >= 0 {
+                        s.rotate_left(k);
+                    } else {
+                        s.rotate_right(k);
+                    }
+                }
+
+                assert_eq!(v, s, "len = {len}, amount = {amount}");
+            }
+        }
+    }
+
+    #[test]
+    fn split_rotated_correct() {
+        for len in [0, 1, 2, 3, 10, 15, 100] {
+            for k in 0..=len {
+                let mut v = seq(len);
+                let mut s = seq(len);
+
+                rotate_left(&mut s, k);
+
+                let (front, back) = split_rotated(&v, k);
+                assert_eq!([front, back].concat(), s, "len = {len}, k = {k}");
+
+                let (front, back) = split_rotated_mut(&mut v, k);
+                front.fill(0);
+                back.fill(1);
+
+                assert!(v[..k].iter().all(|&x| x == 1), "len = {len}, k = {k}");
+                assert!(v[k..].iter().all(|&x| x == 0), "len = {len}, k = {k}");
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "split_rotated: k = 4 > len = 3")]
+    fn split_rotated_out_of_bounds() {
+        split_rotated(&[1, 2, 3], 4);
+    }
+
+    #[test]
+    fn iter_rotated_correct() {
+        for len in [0, 1, 2, 3, 10, 15, 100] {
+            for k in (0..=2 * len).chain([1000]) {
+                let mut v = seq(len);
+                let mut s = seq(len);
+
+                if len > 0 {
+                    s.rotate_left(k % len);
+                }
+
+                assert!(iter_rotated(&v, k).eq(&s), "len = {len}, k = {k}");
+
+                // numbering the elements in rotated order, then rotating, gives `0..len`
+                for (i, x) in iter_rotated_mut(&mut v, k).enumerate() {
+                    *x = i;
+                }
+                wrapping_rotate_left(&mut v, k);
+
+                assert!(v.into_iter().eq(0..len), "len = {len}, k = {k}");
+            }
+        }
+    }
+
+    #[test]
+    fn rotate_into_correct() {
+        for len in [0, 1, 2, 3, 10, 15, 100] {
+            for k in 0..=len {
+                let src: Vec<String> = seq(len).iter().map(|i| i.to_string()).collect();
+                let mut dst = vec![String::from("x"); len];
+
+                let mut s = src.clone();
+                s.rotate_left(k);
+
+                rotate_into(&src, k, &mut dst);
+                assert_eq!(dst, s, "len = {len}, k = {k}");
+
+                let src = seq(len);
+                let mut dst = vec![0; len];
+
+                let mut s = src.clone();
+                s.rotate_left(k);
+
+                rotate_into_copy(&src, k, &mut dst);
+                assert_eq!(dst, s, "len = {len}, k = {k}");
+                assert_eq!(src, seq(len));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "rotate_into_copy: dst len = 2 != src len = 3")]
+    fn rotate_into_len_mismatch() {
+        rotate_into_copy(&[1, 2, 3], 1, &mut [0, 0]);
+    }
+
+    #[test]
+    fn rotate_array_left_correct() {
+        fn check<const N: usize>() {
+            let arr: [usize; N] = core::array::from_fn(|i| i + 1);
+
+            for k in (0..=2 * N + 1).chain([usize::MAX]) {
+                let mut s = arr.to_vec();
+                if N > 0 {
+                    s.rotate_left(k % N);
+                }
+
+                assert_eq!(rotate_array_left(arr, k).to_vec(), s, "N = {N}, k = {k}");
+            }
+        }
+
+        seq_macro::seq!(N in 0..=16 {
+            check::<N>();
+        });
+
+        let arr = [String::from("a"), String::from("b"), String::from("c")];
+        assert_eq!(rotate_array_left(arr, 4), ["b", "c", "a"]);
+    }
+
+    #[test]
+    fn rotate_bytes_left_correct() {
+        for len in (0..=40).chain([1000, 8191, 8192, 8193, 10_000]) {
+            let bytes: Vec<u8> = (0..len).map(|i| i as u8).collect();
+
+            for k in 0..=len {
+                let mut v = bytes.clone();
+                let mut s = bytes.clone();
+
+                rotate_bytes_left(&mut v, k);
+                s.rotate_left(k);
+
+                assert_eq!(v, s, "len = {len}, k = {k}");
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "rotate_bytes_left: k = 4 > len = 3")]
+    fn rotate_bytes_left_out_of_bounds() {
+        rotate_bytes_left(&mut [1, 2, 3], 4);
+    }
+
+    #[test]
+    fn rotate_left_tracking_correct() {
+        for len in (1..=20).chain([100]) {
+            for k in 0..=len {
+                for index in 0..len {
+                    let mut v = seq(len);
+                    let value = v[index];
+
+                    let new_index = rotate_left_tracking(&mut v, k, index);
+
+                    let mut s = seq(len);
+                    s.rotate_left(k);
+
+                    assert_eq!(v, s, "len = {len}, k = {k}");
+                    assert_eq!(v[new_index], value, "len = {len}, k = {k}, index = {index}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rotate_left_tracking_huge_zst() {
+        let len = usize::MAX;
+
+        // SAFETY: any non-null, aligned pointer is valid for any number of zero-sized elements.
+        let s = unsafe {
+            core::slice::from_raw_parts_mut(ptr::NonNull::<()>::dangling().as_ptr(), len)
+        };
+
+        assert_eq!(rotate_left_tracking(s, 2, len - 1), len - 3);
+        assert_eq!(rotate_left_tracking(s, len - 1, len - 1), 0);
+        assert_eq!(rotate_left_tracking(s, len - 1, 0), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "rotate_left_tracking: index = 3 >= len = 3")]
+    fn rotate_left_tracking_out_of_bounds() {
+        rotate_left_tracking(&mut [1, 2, 3], 1, 3);
+    }
+
+    #[test]
+    fn safe_reversal_rotate_left_correct() {
+        sweep_slice(safe_reversal_rotate_left);
+    }
+
+    #[test]
+    #[should_panic(expected = "safe_reversal_rotate_left: k = 4 > len = 3")]
+    fn safe_reversal_rotate_left_out_of_bounds() {
+        safe_reversal_rotate_left(&mut [1, 2, 3], 4);
+    }
+
+    #[test]
+    fn rotate_range_left_correct() {
+        let len = 40;
+
+        for start in 0..=len {
+            for end in start..=len {
+                for k in 0..=end - start {
+                    let mut v = seq(len);
+                    let mut s = seq(len);
+
+                    rotate_range_left(&mut v, start..end, k);
+                    s[start..end].rotate_left(k);
+
+                    assert_eq!(v, s, "range = {start}..{end}, k = {k}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "range end = 16 > len = 15")]
+    fn rotate_range_left_out_of_bounds() {
+        rotate_range_left(&mut seq(15), 10..16, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "k = 6 > range len = 5")]
+    fn rotate_range_left_k_too_large() {
+        rotate_range_left(&mut seq(15), 5..10, 6);
+    }
+
+    #[test]
+    fn rotate_outer_left_correct() {
+        for len in [0, 1, 2, 3, 10, 15, 100] {
+            for k in 0..=len {
+                let mut v: Vec<Vec<usize>> = (0..len).map(|i| seq(i % 7)).collect();
+                let mut s = v.clone();
+
+                let mut ptrs: Vec<*const usize> = v.iter().map(|r| r.as_ptr()).collect();
+
+                rotate_outer_left(&mut v, k);
+                s.rotate_left(k);
+                ptrs.rotate_left(k);
+
+                assert_eq!(v, s, "len = {len}, k = {k}");
+
+                // the rows were moved, not reallocated
+                assert!(
+                    v.iter().map(|r| r.as_ptr()).eq(ptrs),
+                    "len = {len}, k = {k}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "rotate_outer_left: k = 4 > len = 3")]
+    fn rotate_outer_left_out_of_bounds() {
+        rotate_outer_left(&mut [vec![1], vec![2], vec![3]], 4);
+    }
+
+    #[test]
+    fn rotate_split_correct() {
+        for a_len in 0..=12 {
+            for b_len in 0..=12 {
+                for k in 0..=a_len + b_len {
+                    let mut a = seq(a_len);
+                    let mut b: Vec<usize> = (a_len + 1..=a_len + b_len).collect();
+
+                    let mut s = seq(a_len + b_len);
+                    s.rotate_left(k);
+
+                    rotate_split(&mut a, &mut b, k);
+
+                    assert_eq!([a, b].concat(), s, "a = {a_len}, b = {b_len}, k = {k}");
+
+                    // adjacent
+                    let mut v = seq(a_len + b_len);
+                    let (a, b) = v.split_at_mut(a_len);
+
+                    rotate_split(a, b, k);
+
+                    assert_eq!(v, s, "adjacent: a = {a_len}, b = {b_len}, k = {k}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "k = 8 > len = 7")]
+    fn rotate_split_out_of_bounds() {
+        rotate_split(&mut seq(3), &mut seq(4), 8);
+    }
+}
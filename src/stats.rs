@@ -0,0 +1,430 @@
+/*
+Copyright (C) 2023 Valentin Vasilev (3volny@gmail.com).
+*/
+
+/*
+Permission is hereby granted, free of charge, to any person obtaining
+a copy of this software and associated documentation files (the
+"Software"), to deal in the Software without restriction, including
+without limitation the rights to use, copy, modify, merge, publish,
+distribute, sublicense, and/or sell copies of the Software, and to
+permit persons to whom the Software is furnished to do so, subject to
+the following conditions:
+
+The above copyright notice and this permission notice shall be
+included in all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY
+CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,
+TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE
+SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+//! Rotations that count the primitive operations they perform.
+//!
+//! The loops are duplicated from the original rotations, so the counting never gets into their
+//! hot path. Available with the `stats` feature.
+
+use crate::{binary_usize, is_zst, juggle, ptr_edge_rotate};
+use core::cmp;
+use core::ptr;
+
+/// Number of primitive operations performed by a rotation.
+///
+/// A move through a temporary (`read`, `write`, `replace`, `ptr::copy`) is counted as reads and
+/// writes, an exchange of two elements of the range (`ptr::swap`, `swap_nonoverlapping`, reversal)
+/// is counted as a swap.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RotationStats {
+    /// Elements read from the range into a temporary or moved within it, one per element.
+    pub reads: usize,
+    /// Elements written into the range from a temporary or moved within it, one per element.
+    pub writes: usize,
+    /// Pairs of elements of the range exchanged in place, one per pair, not also counted as reads
+    /// and writes.
+    pub swaps: usize,
+}
+
+/// Counted [`ptr_edge_rotate`] for `left <= 2`, `right <= 2` or `left == right`.
+unsafe fn ptr_edge_rotate_counted<T>(
+    left: usize,
+    mid: *mut T,
+    right: usize,
+    stats: &mut RotationStats,
+) {
+    if left == 0 || right == 0 {
+        return;
+    }
+
+    if left == right {
+        stats.swaps += left;
+    } else {
+        // the smallest side goes to temporaries, the rest is shifted
+        stats.reads += left + right;
+        stats.writes += left + right;
+    }
+
+    ptr_edge_rotate(left, mid, right);
+}
+
+/// # Direct rotation (counted)
+///
+/// Same as [`ptr_direct_rotate`](crate::ptr_direct_rotate), returns the number of performed
+/// operations. Every element is moved once through the temporary, so
+/// `reads == writes == left + right`.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+pub unsafe fn ptr_direct_rotate_counted<T>(
+    left: usize,
+    mid: *mut T,
+    right: usize,
+) -> RotationStats {
+    let mut stats = RotationStats::default();
+
+    if is_zst::<T>() {
+        return stats;
+    }
+
+    if right <= 2 || left <= 2 || left == right {
+        ptr_edge_rotate_counted(left, mid, right, &mut stats);
+        return stats;
+    }
+
+    let start = mid.sub(left);
+
+    let mut tmp: T = start.read();
+    stats.reads += 1;
+
+    let mut i = right;
+    let mut gcd = right;
+
+    loop {
+        ptr::swap(&mut tmp, start.add(i));
+        stats.reads += 1;
+        stats.writes += 1;
+
+        if i >= left {
+            i -= left;
+            if i == 0 {
+                start.write(tmp);
+                stats.writes += 1;
+                break;
+            }
+            if i < gcd {
+                gcd = i;
+            }
+        } else {
+            i += right;
+        }
+    }
+
+    for s in 1..gcd {
+        tmp = start.add(s).read();
+        stats.reads += 1;
+
+        i = s + right;
+
+        loop {
+            ptr::swap(&mut tmp, start.add(i));
+            stats.reads += 1;
+            stats.writes += 1;
+
+            if i >= left {
+                i -= left;
+                if i == s {
+                    start.add(s).write(tmp);
+                    stats.writes += 1;
+                    break;
+                }
+            } else {
+                i += right;
+            }
+        }
+    }
+
+    stats
+}
+
+/// # Minimum move rotation (counted)
+///
+/// Same as [`ptr_min_move_rotate`](crate::ptr_min_move_rotate), returns the number of performed
+/// operations. Every element is read and written exactly once, whatever the sides, so
+/// `reads == writes == left + right`.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+pub unsafe fn ptr_min_move_rotate_counted<T>(
+    left: usize,
+    mid: *mut T,
+    right: usize,
+) -> RotationStats {
+    let mut stats = RotationStats::default();
+
+    if is_zst::<T>() || left == 0 || right == 0 {
+        return stats;
+    }
+
+    let cycles = binary_usize(left, right);
+    let moves = juggle(mid.sub(left), left, left + right, cycles);
+
+    // every cycle reads and writes its temporary once, every filled hole is one read and write
+    stats.reads = moves + cycles;
+    stats.writes = moves + cycles;
+
+    stats
+}
+
+/// # Triple reversal rotation (counted)
+///
+/// Same as [`ptr_reversal_rotate`](crate::ptr_reversal_rotate), returns the number of performed
+/// operations: `left / 2 + right / 2 + (left + right) / 2` swaps.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+pub unsafe fn ptr_reversal_rotate_counted<T>(
+    left: usize,
+    mid: *mut T,
+    right: usize,
+) -> RotationStats {
+    let mut stats = RotationStats::default();
+
+    if is_zst::<T>() {
+        return stats;
+    }
+
+    if right <= 2 || left <= 2 || left == right {
+        ptr_edge_rotate_counted(left, mid, right, &mut stats);
+        return stats;
+    }
+
+    let start = mid.sub(left);
+
+    unsafe fn reverse_slice<T>(p: *mut T, size: usize, stats: &mut RotationStats) {
+        core::slice::from_raw_parts_mut(p, size).reverse();
+        stats.swaps += size / 2;
+    }
+
+    reverse_slice(start, left, &mut stats);
+    reverse_slice(mid, right, &mut stats);
+    reverse_slice(start, left + right, &mut stats);
+
+    stats
+}
+
+/// # Piston rotation (counted)
+///
+/// Same as [`ptr_piston_rotate`](crate::ptr_piston_rotate), returns the number of performed
+/// operations.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+pub unsafe fn ptr_piston_rotate_counted<T>(
+    mut left: usize,
+    mid: *mut T,
+    mut right: usize,
+) -> RotationStats {
+    let mut stats = RotationStats::default();
+
+    if is_zst::<T>() {
+        return stats;
+    }
+
+    loop {
+        if left <= 2 {
+            break;
+        }
+
+        while left <= right {
+            ptr::swap_nonoverlapping(mid.sub(left), mid.add(right - left), left);
+            stats.swaps += left;
+            right -= left;
+        }
+
+        if right <= 2 {
+            break;
+        }
+
+        while left >= right {
+            ptr::swap_nonoverlapping(mid, mid.sub(left), right);
+            stats.swaps += right;
+            left -= right;
+        }
+    }
+
+    if left <= 2 || right <= 2 {
+        ptr_edge_rotate_counted(left, mid, right, &mut stats);
+    }
+
+    stats
+}
+
+/// # Contrev rotation (counted)
+///
+/// Same as [`ptr_contrev_rotate`](crate::ptr_contrev_rotate), returns the number of performed
+/// operations.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+pub unsafe fn ptr_contrev_rotate_counted<T>(
+    left: usize,
+    mid: *mut T,
+    right: usize,
+) -> RotationStats {
+    let mut stats = RotationStats::default();
+
+    if is_zst::<T>() {
+        return stats;
+    }
+
+    if left <= 2 || right <= 2 || left == right {
+        ptr_edge_rotate_counted(left, mid, right, &mut stats);
+        return stats;
+    }
+
+    let (mut ls, mut le) = (mid.sub(left), mid.sub(1));
+    let (mut rs, mut re) = (mid, mid.add(right).sub(1));
+
+    let half_min = cmp::min(left, right) / 2;
+    let half_max = cmp::max(left, right) / 2;
+
+    for _ in 0..half_min {
+        // Permutation (ls, le, re, rs)
+        ls.write(rs.replace(re.replace(le.replace(ls.read()))));
+        stats.reads += 4;
+        stats.writes += 4;
+
+        ls = ls.add(1);
+        le = le.sub(1);
+        rs = rs.add(1);
+        re = re.sub(1);
+    }
+
+    if left > right {
+        for _ in 0..half_max - half_min {
+            // (ls, le, re)
+            ls.write(re.replace(le.replace(ls.read())));
+            stats.reads += 3;
+            stats.writes += 3;
+
+            ls = ls.add(1);
+            le = le.sub(1);
+            re = re.sub(1);
+        }
+    } else {
+        for _ in 0..half_max - half_min {
+            // (rs, re, ls)
+            ls.write(rs.replace(re.replace(ls.read())));
+            stats.reads += 3;
+            stats.writes += 3;
+
+            ls = ls.add(1);
+            rs = rs.add(1);
+            re = re.sub(1);
+        }
+    }
+
+    let count = re.offset_from(ls).unsigned_abs() + 1;
+
+    core::slice::from_raw_parts_mut(ls, count).reverse();
+    stats.swaps += count / 2;
+
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{seq, sweep_rotate};
+    use crate::*;
+
+    fn test_correct(rotate: unsafe fn(usize, *mut usize, usize) -> RotationStats) {
+        sweep_rotate(|l, p, r| {
+            unsafe { rotate(l, p, r) };
+        });
+    }
+
+    #[test]
+    fn ptr_direct_rotate_counted_correct() {
+        test_correct(ptr_direct_rotate_counted::<usize>);
+
+        for (l, r) in [(9, 6), (6, 9), (17, 3), (500, 499)] {
+            let mut v = seq(l + r);
+            let stats = unsafe { ptr_direct_rotate_counted(l, v.as_mut_ptr().add(l), r) };
+
+            assert_eq!(
+                stats,
+                RotationStats {
+                    reads: l + r,
+                    writes: l + r,
+                    swaps: 0
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn ptr_min_move_rotate_counted_correct() {
+        test_correct(ptr_min_move_rotate_counted::<usize>);
+
+        for (l, r) in [
+            (1, 15),
+            (2, 2),
+            (9, 6),
+            (6, 9),
+            (17, 3),
+            (500, 500),
+            (500, 499),
+        ] {
+            let mut v = seq(l + r);
+            let stats = unsafe { ptr_min_move_rotate_counted(l, v.as_mut_ptr().add(l), r) };
+
+            assert_eq!(
+                stats,
+                RotationStats {
+                    reads: l + r,
+                    writes: l + r,
+                    swaps: 0
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn ptr_reversal_rotate_counted_correct() {
+        test_correct(ptr_reversal_rotate_counted::<usize>);
+
+        for (l, r) in [(9, 6), (6, 9), (17, 3), (500, 499)] {
+            let mut v = seq(l + r);
+            let stats = unsafe { ptr_reversal_rotate_counted(l, v.as_mut_ptr().add(l), r) };
+
+            assert_eq!(stats.swaps, l / 2 + r / 2 + (l + r) / 2);
+            assert_eq!(stats.reads + stats.writes, 0);
+        }
+    }
+
+    #[test]
+    fn ptr_piston_rotate_counted_correct() {
+        test_correct(ptr_piston_rotate_counted::<usize>);
+    }
+
+    #[test]
+    fn ptr_contrev_rotate_counted_correct() {
+        test_correct(ptr_contrev_rotate_counted::<usize>);
+
+        for (l, r) in [(9, 6), (6, 9), (17, 3), (500, 499)] {
+            let mut v = seq(l + r);
+            let stats = unsafe { ptr_contrev_rotate_counted(l, v.as_mut_ptr().add(l), r) };
+
+            assert_eq!(stats.reads, stats.writes);
+            assert!(stats.swaps <= (l + r) / 2);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+/*
+Copyright (C) 2023 Valentin Vasilev (3volny@gmail.com).
+*/
+
+//! Rotations of streamed sequences that don't fit in memory. Available with the `alloc` feature.
+
+use alloc::vec::Vec;
+
+/// # Streaming rotation
+///
+/// Left-rotates the items of `iter` by `k`: yields the items after the first `k`, then the first
+/// `k` items. Equivalently, `rotate_stream(v.into_iter(), k, cap).collect()` gives the same result
+/// as `v.rotate_left(k)`, but only the first `k` items are ever kept in memory.
+///
+/// ## Algorithm
+///
+/// The first `k` items are read into a buffer of capacity `buffer_cap` as soon as the function
+/// is called. The returned iterator lazily yields the rest of `iter`, then drains the buffer.
+///
+/// ## Panics
+///
+/// This function will panic if `k` is greater than `buffer_cap`, since that would require
+/// buffering more than allowed, or if `iter` ends before `k` items were read, like
+/// [`rotate_left`](crate::rotate_left) panics for `k > len`. Note that `k == len` does _not_
+/// panic and is a no-op rotation.
+///
+/// ## Example
+///
+/// ```text
+///                 k = 5
+/// [ 1  2  3  4  5* 6  7  8  9 ...]  // buffer: [1  2  3  4  5]
+/// [ 6  7  8  9 ...: 1  2  3  4  5]
+/// ```
+pub fn rotate_stream<T, I: Iterator<Item = T>>(
+    mut iter: I,
+    k: usize,
+    buffer_cap: usize,
+) -> impl Iterator<Item = T> {
+    assert!(
+        k <= buffer_cap,
+        "rotate_stream: k = {k} > buffer_cap = {buffer_cap}"
+    );
+
+    let mut buffer = Vec::with_capacity(k);
+    buffer.extend(iter.by_ref().take(k));
+
+    assert!(
+        buffer.len() == k,
+        "rotate_stream: k = {k} > len = {}",
+        buffer.len()
+    );
+
+    iter.chain(buffer)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::seq;
+    use crate::*;
+
+    #[test]
+    fn rotate_stream_correct() {
+        for size in [0, 1, 2, 3, 10, 15, 100] {
+            for k in 0..=size {
+                let mut s = seq(size);
+                s.rotate_left(k);
+
+                let v: Vec<usize> = rotate_stream(seq(size).into_iter(), k, size).collect();
+                assert_eq!(v, s, "size = {size}, k = {k}");
+
+                let v: Vec<usize> = rotate_stream(1..=size, k, k).collect();
+                assert_eq!(v, s, "size = {size}, k = {k}");
+            }
+        }
+    }
+
+    #[test]
+    fn rotate_stream_lazy() {
+        // only the first `k` items are read up front
+        let mut v = rotate_stream(1.., 3, 3);
+
+        assert_eq!(v.next(), Some(4));
+        assert_eq!(v.nth(995), Some(1000));
+    }
+
+    #[test]
+    #[should_panic(expected = "rotate_stream: k = 5 > buffer_cap = 4")]
+    fn rotate_stream_buffer_too_small() {
+        let _ = rotate_stream(seq(10).into_iter(), 5, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "rotate_stream: k = 5 > len = 3")]
+    fn rotate_stream_too_short() {
+        let _ = rotate_stream(seq(3).into_iter(), 5, 10);
+    }
+}
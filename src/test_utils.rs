@@ -0,0 +1,171 @@
+/*
+Copyright (C) 2023 Valentin Vasilev (3volny@gmail.com).
+*/
+
+//! Fixtures shared by the unit tests of all modules.
+
+use std::cell::Cell;
+
+/// `[1, 2, ..., size]`.
+pub fn seq(size: usize) -> Vec<usize> {
+    (1..=size).collect()
+}
+
+/// Splits `s` into `(left, right)` with `left - right = diff`.
+pub fn div(s: usize, diff: usize) -> (usize, usize) {
+    assert!(s >= diff);
+    assert!(s % 2 == diff % 2);
+
+    let r = s / 2 - diff / 2;
+
+    (s - r, r)
+}
+
+/// [`seq`] of `size` and the `(left, mid, right)` arguments for it, with `left - right = diff`.
+pub fn prepare(size: usize, diff: usize) -> (Vec<usize>, (usize, *mut usize, usize)) {
+    let (l, r) = div(size, diff);
+    let mut v = seq(size);
+
+    let p = unsafe { v.as_mut_ptr().add(l) };
+
+    (v, (l, p, r))
+}
+
+/// Rotates a [`seq`] of `size` left by `diff` more than right, and back.
+pub fn case(mut rotate: impl FnMut(usize, *mut usize, usize), size: usize, diff: usize) {
+    let (vec, (l, p, r)) = prepare(size, diff);
+
+    let mut s = seq(size);
+
+    s.rotate_left(l);
+    rotate(l, p, r);
+
+    assert_eq!(vec, s, "size = {size}, diff = {diff}");
+
+    rotate(r, unsafe { p.sub(diff) }, l);
+
+    s.rotate_right(l);
+    assert_eq!(vec, s, "size = {size}, diff = {diff}");
+}
+
+/// Runs `rotate` on the hand-picked [`case`]s.
+pub fn test_correct(rotate: unsafe fn(left: usize, mid: *mut usize, right: usize)) {
+    test_correct_with(|l, p, r| unsafe { rotate(l, p, r) });
+}
+
+/// [`test_correct`] for a closure, e.g. one that passes a buffer.
+pub fn test_correct_with(mut rotate: impl FnMut(usize, *mut usize, usize)) {
+    // --empty--
+    case(&mut rotate, 0, 0);
+
+    // --empty--
+    case(&mut rotate, 2, 0);
+
+    // 1  2  3  4  5  6 (7  8  9)10 11 12 13 14 15
+    case(&mut rotate, 15, 3);
+
+    // 1  2  3  4  5 (6  7  8  9 10)11 12 13 14 15
+    case(&mut rotate, 15, 5);
+
+    // 1  2  3  4  5  6  7 (8) 9 10 11 12 13 14 15
+    case(&mut rotate, 15, 1);
+
+    // 1  2  3  4  5  6  7)(8  9 10 11 12 13 14
+    case(&mut rotate, 14, 0);
+
+    // 1  2  3  4 (5  6  7  8  9 10 11)12 13 14 15
+    case(&mut rotate, 15, 7);
+
+    // 1 (2  3  4  5  6  7  8  9 10 11 12 13 14)15
+    case(&mut rotate, 15, 13);
+
+    //(1  2  3  4  5  6  7  8  9 10 11 12 13 14 15)
+    case(&mut rotate, 15, 15);
+
+    // 1  2  3 (4  5  6  7  8  9)10 11 12
+    case(&mut rotate, 12, 6);
+
+    // 1  2  3  4  5  6  7 (8  9 10)11 12 13 14 15 16 17
+    case(&mut rotate, 17, 3);
+
+    //(1  2  3  4  5  6  7  8  9 10 11 12 13 14 15)
+    case(&mut rotate, 100_000, 0);
+}
+
+/// Calls `check(len, k)` for every `len` up to `40`, `100` and `1000`, and every `k` in
+/// `0..=len`.
+pub fn sweep(mut check: impl FnMut(usize, usize)) {
+    for len in (0..=40).chain([100, 1000]) {
+        for k in 0..=len {
+            check(len, k);
+        }
+    }
+}
+
+/// [`sweep`] that checks `rotate(left, mid, right)` on a [`seq`] against `<[T]>::rotate_left`.
+pub fn sweep_rotate(mut rotate: impl FnMut(usize, *mut usize, usize)) {
+    sweep(|len, left| {
+        let mut v = seq(len);
+        let mut s = seq(len);
+
+        rotate(left, unsafe { v.as_mut_ptr().add(left) }, len - left);
+        s.rotate_left(left);
+
+        assert_eq!(v, s, "len = {len}, left = {left}");
+    });
+}
+
+/// [`sweep`] that checks `rotate(slice, k)` on a [`seq`] against `<[T]>::rotate_left`.
+pub fn sweep_slice(mut rotate: impl FnMut(&mut [usize], usize)) {
+    sweep(|len, k| {
+        let mut v = seq(len);
+        let mut s = seq(len);
+
+        rotate(&mut v, k);
+        s.rotate_left(k);
+
+        assert_eq!(v, s, "len = {len}, k = {k}");
+    });
+}
+
+/// Element that counts live instances in `live`. It can't be cloned or copied, so a duplicated
+/// or lost value shows up as a wrong count.
+pub struct Counted<'a>(pub usize, &'a Cell<isize>);
+
+impl<'a> Counted<'a> {
+    pub fn new(i: usize, live: &'a Cell<isize>) -> Self {
+        live.set(live.get() + 1);
+
+        Counted(i, live)
+    }
+}
+
+impl Drop for Counted<'_> {
+    fn drop(&mut self) {
+        self.1.set(self.1.get() - 1);
+    }
+}
+
+/// `Counted(0), Counted(1), ..., Counted(len - 1)`.
+pub fn counted(len: usize, live: &Cell<isize>) -> Vec<Counted<'_>> {
+    (0..len).map(|i| Counted::new(i, live)).collect()
+}
+
+/// [`sweep`] that checks `rotate(left, mid, right)` on [`counted`] elements: the order is right,
+/// and no element is duplicated or dropped.
+pub fn sweep_counted(mut rotate: impl FnMut(usize, *mut Counted<'_>, usize)) {
+    sweep(|len, left| {
+        let live = Cell::new(0);
+        let mut v = counted(len, &live);
+
+        rotate(left, unsafe { v.as_mut_ptr().add(left) }, len - left);
+        assert_eq!(live.get(), len as isize, "len = {len}, left = {left}");
+
+        let mut s: Vec<usize> = (0..len).collect();
+        s.rotate_left(left);
+        assert!(v.iter().map(|c| c.0).eq(s), "len = {len}, left = {left}");
+
+        drop(v);
+        assert_eq!(live.get(), 0, "len = {len}, left = {left}");
+    });
+}
@@ -0,0 +1,461 @@
+/*
+Copyright (C) 2023 Valentin Vasilev (3volny@gmail.com).
+*/
+
+/*
+Permission is hereby granted, free of charge, to any person obtaining
+a copy of this software and associated documentation files (the
+"Software"), to deal in the Software without restriction, including
+without limitation the rights to use, copy, modify, merge, publish,
+distribute, sublicense, and/or sell copies of the Software, and to
+permit persons to whom the Software is furnished to do so, subject to
+the following conditions:
+
+The above copyright notice and this permission notice shall be
+included in all copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.
+IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY
+CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT,
+TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE
+SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+*/
+
+//! Rotations that record the moves they make.
+//!
+//! Every move is recorded as a pair `(from, to)`: the element at `from` is moved to `to`, with
+//! indices relative to `mid - left`. The rotations keep at most two elements outside of the
+//! range, these temporaries are denoted by [`TMP`] and [`TMP2`]. Replaying the trace with
+//! [`replay`] on any slice of `left + right` elements gives the same permutation as the
+//! rotation, so it can be used to print and check the diagrams from the documentation. Available
+//! with the `trace` feature.
+
+use crate::is_zst;
+use alloc::vec::Vec;
+use core::cmp;
+use core::mem::MaybeUninit;
+use core::ptr;
+
+/// Index of the first temporary in a trace.
+pub const TMP: usize = usize::MAX;
+
+/// Index of the second temporary in a trace.
+pub const TMP2: usize = usize::MAX - 1;
+
+/// Range `[start, ...)` with two temporaries that records the moves made on it.
+struct Tracer<'a, T> {
+    start: *mut T,
+    tmp: [MaybeUninit<T>; 2],
+    trace: &'a mut Vec<(usize, usize)>,
+}
+
+impl<'a, T> Tracer<'a, T> {
+    fn new(start: *mut T, trace: &'a mut Vec<(usize, usize)>) -> Self {
+        Self {
+            start,
+            tmp: [MaybeUninit::uninit(), MaybeUninit::uninit()],
+            trace,
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn slot(&mut self, i: usize) -> *mut T {
+        if i >= TMP2 {
+            self.tmp[TMP - i].as_mut_ptr()
+        } else {
+            self.start.add(i)
+        }
+    }
+
+    /// Moves the element at `from` to `to` and records it.
+    #[inline(always)]
+    unsafe fn mov(&mut self, from: usize, to: usize) {
+        let (src, dst) = (self.slot(from), self.slot(to));
+
+        ptr::copy_nonoverlapping(src, dst, 1);
+        self.trace.push((from, to));
+    }
+
+    /// Exchanges the elements at `i` and `j` through [`TMP`], like `ptr::swap`.
+    #[inline(always)]
+    unsafe fn swap(&mut self, i: usize, j: usize) {
+        self.mov(i, TMP);
+        self.mov(j, i);
+        self.mov(TMP, j);
+    }
+
+    /// Puts the element carried in the temporary `carry` at `i`, like `ptr::replace`: the
+    /// element at `i` goes to the other temporary, which is returned.
+    #[inline(always)]
+    unsafe fn replace(&mut self, i: usize, carry: usize) -> usize {
+        let free = if carry == TMP { TMP2 } else { TMP };
+
+        self.mov(i, free);
+        self.mov(carry, i);
+
+        free
+    }
+}
+
+/// Traced [`ptr_edge_rotate`](crate::ptr_edge_rotate) for `left <= 2`, `right <= 2` or
+/// `left == right`: equal sides are swapped, otherwise the smallest side is put aside, the other
+/// one is shifted and the smallest side is put back.
+unsafe fn edge_rotate_trace<T>(left: usize, right: usize, t: &mut Tracer<'_, T>) {
+    debug_assert!(left <= 2 || right <= 2 || left == right);
+
+    if left == 0 || right == 0 {
+        return;
+    }
+
+    let size = left + right;
+
+    if left == right {
+        for i in 0..left {
+            t.swap(i, left + i);
+        }
+    } else if left <= 2 {
+        t.mov(0, TMP);
+        if left == 2 {
+            t.mov(1, TMP2);
+        }
+
+        for i in left..size {
+            t.mov(i, i - left);
+        }
+
+        t.mov(TMP, right);
+        if left == 2 {
+            t.mov(TMP2, right + 1);
+        }
+    } else {
+        t.mov(left, TMP);
+        if right == 2 {
+            t.mov(left + 1, TMP2);
+        }
+
+        for i in (0..left).rev() {
+            t.mov(i, i + right);
+        }
+
+        t.mov(TMP, 0);
+        if right == 2 {
+            t.mov(TMP2, 1);
+        }
+    }
+}
+
+/// Replays the `trace` on `s`.
+///
+/// ## Panics
+///
+/// Panics if an index of the `trace` is out of bounds or a temporary is read before it is
+/// written.
+///
+/// ## Example
+///
+/// ```rust
+/// use rust_rotations::{ptr_reversal_rotate_trace, replay};
+///
+/// let mut v = [1, 2, 3, 4, 5, 6, 7];
+/// let mut trace = Vec::new();
+///
+/// unsafe { ptr_reversal_rotate_trace(3, v.as_mut_ptr().add(3), 4, &mut trace) };
+///
+/// let mut w = [1, 2, 3, 4, 5, 6, 7];
+/// replay(&mut w, &trace);
+///
+/// assert_eq!(v, w);
+/// ```
+pub fn replay<T: Clone>(s: &mut [T], trace: &[(usize, usize)]) {
+    let mut tmp: [Option<T>; 2] = [None, None];
+
+    for &(from, to) in trace {
+        let x = if from >= TMP2 {
+            tmp[TMP - from].take().expect("replay: empty temporary")
+        } else {
+            s[from].clone()
+        };
+
+        if to >= TMP2 {
+            tmp[TMP - to] = Some(x);
+        } else {
+            s[to] = x;
+        }
+    }
+}
+
+/// # Direct rotation (traced)
+///
+/// Same as [`ptr_direct_rotate`](crate::ptr_direct_rotate). Every step of a cycle exchanges the
+/// carried element with the next slot, so it alternates between [`TMP`] and [`TMP2`].
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+pub unsafe fn ptr_direct_rotate_trace<T>(
+    left: usize,
+    mid: *mut T,
+    right: usize,
+    trace: &mut Vec<(usize, usize)>,
+) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    let mut t = Tracer::new(mid.sub(left), trace);
+
+    if right <= 2 || left <= 2 || left == right {
+        edge_rotate_trace(left, right, &mut t);
+        return;
+    }
+
+    t.mov(0, TMP);
+
+    let mut carry = TMP;
+    let mut i = right;
+    let mut gcd = right;
+
+    loop {
+        carry = t.replace(i, carry);
+
+        if i >= left {
+            i -= left;
+            if i == 0 {
+                t.mov(carry, 0);
+                break;
+            }
+            if i < gcd {
+                gcd = i;
+            }
+        } else {
+            i += right;
+        }
+    }
+
+    for s in 1..gcd {
+        t.mov(s, TMP);
+
+        carry = TMP;
+        i = s + right;
+
+        loop {
+            carry = t.replace(i, carry);
+
+            if i >= left {
+                i -= left;
+                if i == s {
+                    t.mov(carry, s);
+                    break;
+                }
+            } else {
+                i += right;
+            }
+        }
+    }
+}
+
+/// # Triple reversal rotation (traced)
+///
+/// Same as [`ptr_reversal_rotate`](crate::ptr_reversal_rotate). Every exchange of a reversal
+/// goes through [`TMP`].
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+pub unsafe fn ptr_reversal_rotate_trace<T>(
+    left: usize,
+    mid: *mut T,
+    right: usize,
+    trace: &mut Vec<(usize, usize)>,
+) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    let mut t = Tracer::new(mid.sub(left), trace);
+
+    if right <= 2 || left <= 2 || left == right {
+        edge_rotate_trace(left, right, &mut t);
+        return;
+    }
+
+    unsafe fn reverse<T>(t: &mut Tracer<'_, T>, from: usize, to: usize) {
+        let (mut i, mut j) = (from, to - 1);
+
+        while i < j {
+            t.swap(i, j);
+            i += 1;
+            j -= 1;
+        }
+    }
+
+    reverse(&mut t, 0, left);
+    reverse(&mut t, left, left + right);
+    reverse(&mut t, 0, left + right);
+}
+
+/// # Contrev rotation (traced)
+///
+/// Same as [`ptr_contrev_rotate`](crate::ptr_contrev_rotate). The permutation
+/// `(ls, le, re, rs)` is a chain of `replace`s, so the carried element alternates between
+/// [`TMP`] and [`TMP2`]. The center is reversed through [`TMP`].
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+pub unsafe fn ptr_contrev_rotate_trace<T>(
+    left: usize,
+    mid: *mut T,
+    right: usize,
+    trace: &mut Vec<(usize, usize)>,
+) {
+    if is_zst::<T>() {
+        return;
+    }
+
+    let mut t = Tracer::new(mid.sub(left), trace);
+
+    if left <= 2 || right <= 2 || left == right {
+        edge_rotate_trace(left, right, &mut t);
+        return;
+    }
+
+    let (mut ls, mut le) = (0, left - 1);
+    let (mut rs, mut re) = (left, left + right - 1);
+
+    let half_min = cmp::min(left, right) / 2;
+    let half_max = cmp::max(left, right) / 2;
+
+    for _ in 0..half_min {
+        // Permutation (ls, le, re, rs)
+        t.mov(ls, TMP);
+        let carry = t.replace(le, TMP);
+        let carry = t.replace(re, carry);
+        let carry = t.replace(rs, carry);
+        t.mov(carry, ls);
+
+        ls += 1;
+        le -= 1;
+        rs += 1;
+        re -= 1;
+    }
+
+    if left > right {
+        for _ in 0..half_max - half_min {
+            // (ls, le, re)
+            t.mov(ls, TMP);
+            let carry = t.replace(le, TMP);
+            let carry = t.replace(re, carry);
+            t.mov(carry, ls);
+
+            ls += 1;
+            le -= 1;
+            re -= 1;
+        }
+    } else {
+        for _ in 0..half_max - half_min {
+            // (rs, re, ls)
+            t.mov(ls, TMP);
+            let carry = t.replace(re, TMP);
+            let carry = t.replace(rs, carry);
+            t.mov(carry, ls);
+
+            ls += 1;
+            rs += 1;
+            re -= 1;
+        }
+    }
+
+    while ls < re {
+        // (re, ls)
+        t.swap(ls, re);
+
+        ls += 1;
+        re -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{seq, sweep};
+    use crate::*;
+
+    fn test_correct(
+        rotate: unsafe fn(usize, *mut usize, usize, &mut Vec<(usize, usize)>),
+        reference: unsafe fn(usize, *mut usize, usize),
+    ) {
+        sweep(|size, l| {
+            let mut trace = Vec::new();
+
+            let mut v = seq(size);
+            unsafe { rotate(l, v.as_mut_ptr().add(l), size - l, &mut trace) };
+
+            let mut s = seq(size);
+            s.rotate_left(l);
+            assert_eq!(v, s, "size = {size}, left = {l}");
+
+            let mut r = seq(size);
+            unsafe { reference(l, r.as_mut_ptr().add(l), size - l) };
+            assert_eq!(v, r, "size = {size}, left = {l}");
+
+            let mut identity: Vec<usize> = (0..size).collect();
+            replay(&mut identity, &trace);
+
+            let expected: Vec<usize> = v.iter().map(|x| x - 1).collect();
+            assert_eq!(identity, expected, "size = {size}, left = {l}");
+        });
+    }
+
+    #[test]
+    fn ptr_direct_rotate_trace_correct() {
+        test_correct(ptr_direct_rotate_trace::<usize>, ptr_direct_rotate::<usize>);
+    }
+
+    #[test]
+    fn ptr_reversal_rotate_trace_correct() {
+        test_correct(
+            ptr_reversal_rotate_trace::<usize>,
+            ptr_reversal_rotate::<usize>,
+        );
+    }
+
+    #[test]
+    fn ptr_contrev_rotate_trace_correct() {
+        test_correct(
+            ptr_contrev_rotate_trace::<usize>,
+            ptr_contrev_rotate::<usize>,
+        );
+    }
+
+    #[test]
+    fn edge_rotate_trace_moves() {
+        let mut v = seq(5);
+        let mut trace = Vec::new();
+
+        unsafe { ptr_direct_rotate_trace(1, v.as_mut_ptr().add(1), 4, &mut trace) };
+
+        assert_eq!(trace, [(0, TMP), (1, 0), (2, 1), (3, 2), (4, 3), (TMP, 4)]);
+    }
+
+    #[test]
+    fn ptr_direct_rotate_trace_writes_once() {
+        for (left, right) in [(9, 6), (7, 5), (3, 12), (20, 8)] {
+            let mut v = seq(left + right);
+            let mut trace = Vec::new();
+
+            unsafe { ptr_direct_rotate_trace(left, v.as_mut_ptr().add(left), right, &mut trace) };
+
+            let mut writes = vec![0; left + right];
+            for &(_, to) in trace.iter().filter(|&&(_, to)| to < TMP2) {
+                writes[to] += 1;
+            }
+
+            assert!(
+                writes.iter().all(|&w| w == 1),
+                "{left}, {right}: {writes:?}"
+            );
+        }
+    }
+}
@@ -23,11 +23,106 @@ TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE
 SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 */
 
-use std::mem::size_of;
-use std::mem::MaybeUninit;
-use std::ptr;
-use std::ptr::copy_nonoverlapping;
-use std::slice;
+#[cfg(feature = "nightly")]
+use core::mem::SizedTypeProperties;
+
+use core::cmp;
+use core::mem::size_of;
+use core::mem::MaybeUninit;
+use core::ptr;
+use core::ptr::copy_nonoverlapping;
+use core::slice;
+
+#[cfg(feature = "simd")]
+use core::simd::{Simd, SimdElement};
+
+/// Size limit (in `usize`s) of the type, below which the element-by-element [`copy`] is
+/// preferred over `core::ptr::copy` when shifting left (see [`shift_by`]) and when
+/// [`block_copy`] moves by one element.
+///
+/// Tuned with the `copies` benchmark. It is `15`, not `18`: `15 * size_of::<usize>()` is the
+/// cut-off `shift_left` was tuned with and always used, so every `T` keeps its strategy.
+pub const COPY_ELEMENTWISE_LIMIT: usize = 15;
+
+/// Number of `usize`-sized elements, from which a shift left is done with [`byte_copy`]
+/// (see [`shift_by`]).
+///
+/// Tuned with the `copies` benchmark.
+pub const BYTE_COPY_LEFT_COUNT: usize = 15;
+
+/// Number of `usize`-sized elements, from which a shift right is done with [`byte_copy`]
+/// (see [`shift_by`]).
+///
+/// Tuned with the `copies` benchmark.
+pub const BYTE_COPY_RIGHT_COUNT: usize = 200;
+
+/// Size limit (in `usize`s) of the type, below which the element-by-element [`copy`] is
+/// preferred over [`byte_copy`] when shifting right (see [`shift_by`]).
+///
+/// Tuned with the `copies` benchmark.
+pub const COPY_ELEMENTWISE_RIGHT_LIMIT: usize = 10;
+
+/// Size (in bytes) of a shift in [`ptr_edge_rotate`](crate::ptr_edge_rotate), from which it is
+/// done with `core::ptr::copy` (`memmove`) instead of [`shift_left`] or [`shift_right`].
+///
+/// Tuned with the `EdgeShift` benchmark.
+pub const EDGE_MEMMOVE_BYTES: usize = 4096;
+
+/// Assumed size (in bytes) of the L2 cache, used to size the tiles of
+/// [`ptr_tiled_reversal_rotate`](crate::ptr_tiled_reversal_rotate) (see
+/// [`reversal_tile`](crate::reversal_tile)). Most current CPUs have `1-2 MiB` per core.
+pub const L2_CACHE_BYTES: usize = 1 << 20;
+
+/// Returns `true` if `core::ptr::copy` is preferred over the element-by-element [`copy`]
+/// for `T`, that is if `size_of::<T>() >= COPY_ELEMENTWISE_LIMIT * size_of::<usize>()`.
+#[inline(always)]
+pub const fn prefers_ptr_copy<T>() -> bool {
+    size_of::<T>() >= COPY_ELEMENTWISE_LIMIT * size_of::<usize>()
+}
+
+/// Returns the maximum `left + right` supported by the rotations of `T`.
+///
+/// Pointer offsets must fit in `isize` bytes, so a range can't be longer than
+/// `isize::MAX / size_of::<T>()` elements (unbounded for zero-sized types). Any range inside a
+/// single allocation satisfies it; the rotations never compute an offset outside
+/// `[mid - left, mid + right]`.
+#[inline(always)]
+pub const fn max_rotation_len<T>() -> usize {
+    if size_of::<T>() == 0 {
+        usize::MAX
+    } else {
+        isize::MAX as usize / size_of::<T>()
+    }
+}
+
+/// Checks, in debug builds only, that `left + right` does not exceed [`max_rotation_len`].
+#[inline(always)]
+pub(crate) fn debug_check_len<T>(left: usize, right: usize, name: &str) {
+    debug_assert!(
+        left.checked_add(right)
+            .is_some_and(|len| len <= max_rotation_len::<T>()),
+        "{name}: left + right = {left} + {right} > {}",
+        max_rotation_len::<T>()
+    );
+}
+
+/// Returns `true` if `T` is a zero-sized type.
+///
+/// Uses `T::IS_ZST` with the `nightly` feature and `size_of::<T>() == 0` otherwise.
+#[cfg(feature = "nightly")]
+#[inline(always)]
+pub(crate) const fn is_zst<T>() -> bool {
+    T::IS_ZST
+}
+
+/// Returns `true` if `T` is a zero-sized type.
+///
+/// Uses `T::IS_ZST` with the `nightly` feature and `size_of::<T>() == 0` otherwise.
+#[cfg(not(feature = "nightly"))]
+#[inline(always)]
+pub(crate) const fn is_zst<T>() -> bool {
+    size_of::<T>() == 0
+}
 
 /// # Reverse slice
 ///
@@ -45,12 +140,153 @@ use std::slice;
 ///            └─────────────────┘
 /// [ 1  .  3 10  9  8  7  6  5  4 11  .  .  . 15]
 /// ```
+///
+/// With the `simd` feature `u8`, `u16` and `u32` are reversed with `core::simd` lanes: blocks are
+/// loaded from both ends, shuffled and stored crosswise, and the middle that is shorter than two
+/// blocks is reversed as usual. Any other `T` (which may contain padding bytes) and builds
+/// without the feature use `<[T]>::reverse`.
 #[inline(always)]
 pub unsafe fn reverse_slice<T>(p: *mut T, count: usize) {
-    let slice = slice::from_raw_parts_mut(p, count);
-    slice.reverse();
+    #[cfg(feature = "simd")]
+    <T as Lanes>::reverse(p, count);
+
+    #[cfg(not(feature = "simd"))]
+    slice::from_raw_parts_mut(p, count).reverse();
 }
 
+/// # Reverse slice (custom swap)
+///
+/// Reverse slice `[p, p+count)` with a caller-provided `swap`, e.g. a field-wise swap for types
+/// where `ptr::swap` is suboptimal. The pairs are swapped from the ends inward, `count / 2` calls
+/// in total; the middle element of an odd `count` is not touched.
+///
+/// Without the `simd` feature [`reverse_slice`] is `<[T]>::reverse`, which is what `ptr::swap`
+/// pairs compile to anyway.
+///
+/// ## Safety
+///
+/// 1. The specified range must be valid for reading and writing;
+/// 2. `swap(a, b)` is only called with distinct, non-overlapping `a` and `b` from the range, and
+///    must exchange the values behind them exactly as `ptr::swap` would: without reading or
+///    writing outside of `*a` and `*b`, without dropping or duplicating them, and without
+///    panicking.
+pub unsafe fn reverse_slice_by<T>(p: *mut T, count: usize, swap: impl Fn(*mut T, *mut T)) {
+    if count < 2 {
+        return;
+    }
+
+    let mut start = p;
+    let mut end = p.add(count - 1);
+
+    for _ in 0..count / 2 {
+        swap(start, end);
+        start = start.add(1);
+        end = end.sub(1);
+    }
+}
+
+/// # Reverse blocks
+///
+/// Reverse the order of `count` blocks of `block_size` elements in `[p, p + count * block_size)`,
+/// keeping the order of elements inside every block. Blocks are swapped from the ends inward
+/// with `ptr::swap_nonoverlapping`.
+///
+/// ## Safety
+///
+/// The region `[p, p + count * block_size)` must be valid for reading and writing.
+///
+/// ## Example
+///
+/// ```text
+///           count = 3, block_size = 3
+/// [ 1  2  3  4  5  6  7  8  9 10 11 12 13 14 15]  // reverse blocks
+///            └─────┘  └─────┘  └─────┘
+/// [ 1  .  3 10 11 12  7  8  9  4  5  6 13  . 15]
+/// ```
+pub unsafe fn reverse_blocks<T>(p: *mut T, count: usize, block_size: usize) {
+    if count < 2 {
+        return;
+    }
+
+    let mut start = p;
+    let mut end = p.add((count - 1) * block_size);
+
+    for _ in 0..count / 2 {
+        ptr::swap_nonoverlapping(start, end, block_size);
+        start = start.add(block_size);
+        end = end.sub(block_size);
+    }
+}
+
+/// Swaps reversed `N`-lane SIMD blocks from both ends of `[p, p+count)` while at least two
+/// blocks fit. Returns the number of elements finalized on each side.
+#[cfg(feature = "simd")]
+#[inline(always)]
+unsafe fn simd_reverse<U: SimdElement, const N: usize>(p: *mut U, count: usize) -> usize {
+    let mut done = 0;
+
+    while count - 2 * done >= 2 * N {
+        let start = p.add(done);
+        let end = p.add(count - done - N);
+
+        let a = Simd::<U, N>::from_array(start.cast::<[U; N]>().read_unaligned());
+        let b = Simd::<U, N>::from_array(end.cast::<[U; N]>().read_unaligned());
+
+        start
+            .cast::<[U; N]>()
+            .write_unaligned(b.reverse().to_array());
+        end.cast::<[U; N]>().write_unaligned(a.reverse().to_array());
+
+        done += N;
+    }
+
+    done
+}
+
+/// `core::simd` paths of [`reverse_slice`] and [`swap_halves`], picked by specialization: `u8`,
+/// `u16` and `u32` have no padding bytes and are moved as lanes, any other `T` keeps the scalar
+/// path.
+#[cfg(feature = "simd")]
+trait Lanes: Sized {
+    unsafe fn reverse(p: *mut Self, count: usize);
+
+    unsafe fn swap_halves(a: *mut Self, b: *mut Self, count: usize);
+}
+
+#[cfg(feature = "simd")]
+impl<T> Lanes for T {
+    #[inline(always)]
+    default unsafe fn reverse(p: *mut T, count: usize) {
+        slice::from_raw_parts_mut(p, count).reverse();
+    }
+
+    #[inline(always)]
+    default unsafe fn swap_halves(a: *mut T, b: *mut T, count: usize) {
+        ptr::swap_nonoverlapping(a, b, count);
+    }
+}
+
+macro_rules! impl_lanes {
+    ($($t:ty: $n:literal),*) => {$(
+        #[cfg(feature = "simd")]
+        impl Lanes for $t {
+            #[inline(always)]
+            unsafe fn reverse(p: *mut $t, count: usize) {
+                let done = simd_reverse::<$t, $n>(p, count);
+
+                slice::from_raw_parts_mut(p.add(done), count - 2 * done).reverse();
+            }
+
+            #[inline(always)]
+            unsafe fn swap_halves(a: *mut $t, b: *mut $t, count: usize) {
+                simd_swap_bytes(a.cast(), b.cast(), count * size_of::<$t>());
+            }
+        }
+    )*};
+}
+
+impl_lanes!(u8: 32, u16: 16, u32: 8);
+
 /// # Copy (may overlap)
 ///
 /// Copy region `[src, src + count)` to `[dst, dst + count)` element by element.
@@ -90,19 +326,73 @@ pub unsafe fn copy<T>(src: *const T, dst: *mut T, count: usize) {
         ptr::write(dst, ptr::read(src));
     }
 
-    if src == dst {
-        return;
-    } else if src > dst {
+    if src > dst {
         for i in 0..count {
             _copy(src, dst, i);
         }
-    } else {
+    } else if src < dst {
         for i in (0..count).rev() {
             _copy(src, dst, i);
         }
     }
 }
 
+/// # Copy overlapping
+///
+/// Copy region `[src, src + count)` to `[dst, dst + count)` element by element, with exactly the
+/// semantics of `core::ptr::copy`: the result is as if the source was first copied to a temporary
+/// and then to the destination. Same as [`copy`], stated as a contract the rotations can rely on.
+///
+/// The direction is chosen so that no source element is overwritten before it is read:
+///
+/// ```text
+///  src vs dst   | direction
+/// --------------+--------------------------
+///  src > dst    | --> from the first element
+///  src < dst    | <-- from the last element
+///  src == dst   | nothing to do
+/// ```
+///
+/// ## Safety
+///
+/// * The region `[src, src + count)` must be valid for reading;
+/// * the region `[dst, dst + count)` must be valid for writing.
+#[inline(always)]
+pub unsafe fn copy_overlapping<T>(src: *const T, dst: *mut T, count: usize) {
+    copy(src, dst, count);
+}
+
+/// # Copy (auto)
+///
+/// Copy region `[src, src + count)` to `[dst, dst + count)` with the strategy that suits `T` and
+/// the overlap, with the semantics of `core::ptr::copy`. Keeps the copy policy of the rotations
+/// in one place:
+///
+/// ```text
+///  condition                     | strategy
+/// -------------------------------+------------------------------
+///  prefers_ptr_copy::<T>()       | core::ptr::copy (memmove)
+///  |dst - src| >= count          | core::ptr::copy_nonoverlapping
+///  otherwise                     | copy, element by element
+/// ```
+///
+/// See [`prefers_ptr_copy`] and the `copies` benchmark.
+///
+/// ## Safety
+///
+/// * The region `[src, src + count)` must be valid for reading;
+/// * the region `[dst, dst + count)` must be valid for writing.
+#[inline(always)]
+pub unsafe fn copy_auto<T>(src: *const T, dst: *mut T, count: usize) {
+    if prefers_ptr_copy::<T>() {
+        ptr::copy(src, dst, count);
+    } else if (dst as usize).abs_diff(src as usize) >= count * size_of::<T>() {
+        copy_nonoverlapping(src, dst, count);
+    } else {
+        copy(src, dst, count);
+    }
+}
+
 /// # Copy (may overlap)
 ///
 /// Copy region `[src, src + count)` to `[dst, dst + count)` byte by byte.
@@ -119,15 +409,50 @@ pub unsafe fn byte_copy<T>(src: *const T, dst: *mut T, count: usize) {
     copy(src, dst, count * size_of::<T>());
 }
 
-/// # Copy (may overlap)
+/// # Copy (smart)
+///
+/// Copy region `[src, src + count)` to `[dst, dst + count)` with [`byte_copy`] or [`copy`],
+/// depending on the size of `T`:
 ///
-/// Copy region `[src, src + count)` to `[dst, dst + count)` block by block.
+/// ```text
+///  condition                                      | strategy
+/// ------------------------------------------------+-----------------------------
+///  size_of::<T>() % size_of::<usize>() != 0 and   | byte_copy, byte by byte
+///  !prefers_ptr_copy::<T>()                       |
+///  otherwise                                      | copy, element by element
+/// ```
+///
+/// Element-wise moves of a type whose size is not a multiple of a word can't be done in whole
+/// words, while the byte loop is vectorized. In the `copies` benchmark (`"Copy bytes"`, `1000`
+/// elements) `byte_copy` is `2-6x` faster for `[u8; 3]`, `[u8; 5]`, `[u8; 12]`, `[u8; 20]`, and on
+/// par or slower for word-multiple sizes (`u64`, `[u8; 16]`, `[u8; 24]`).
 ///
 /// Regions could overlap.
 ///
 /// ## Safety
 ///
 /// The specified range must be valid for reading and writing.
+#[inline(always)]
+pub unsafe fn copy_smart<T>(src: *const T, dst: *mut T, count: usize) {
+    if !size_of::<T>().is_multiple_of(size_of::<usize>()) && !prefers_ptr_copy::<T>() {
+        byte_copy(src, dst, count);
+    } else {
+        copy(src, dst, count);
+    }
+}
+
+/// # Copy (may overlap)
+///
+/// Copy region `[src, src + count)` to `[dst, dst + count)` block by block. A shift by one
+/// element has no blocks to copy, so it is done with `core::ptr::copy` or element by element,
+/// as [`prefers_ptr_copy`] decides.
+///
+/// Regions could overlap.
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing. In particular,
+/// `|dst - src| + count` can't exceed [`max_rotation_len`], which is checked in debug builds.
 ///
 /// ## Example
 ///
@@ -173,52 +498,104 @@ pub unsafe fn byte_copy<T>(src: *const T, dst: *mut T, count: usize) {
 pub unsafe fn block_copy<T>(src: *const T, dst: *mut T, count: usize) {
     let block_size = dst.offset_from(src).unsigned_abs();
 
+    debug_check_len::<T>(block_size, count, "block_copy");
+
     if src == dst {
         return;
-    } else if block_size == 1 {
-        copy(src, dst, count);
+    }
+
+    if block_size == 1 {
+        if prefers_ptr_copy::<T>() {
+            ptr::copy(src, dst, count);
+        } else {
+            copy(src, dst, count);
+        }
     } else if block_size > count {
         copy_nonoverlapping(src, dst, count);
     } else {
-        let mut s = src;
-        let mut d = dst;
+        block_copy_sized(src, dst, count, block_size);
+    }
+}
+
+/// # Block copy (sized)
+///
+/// Copies region `[src, src + count)` to `[dst, dst + count)` by blocks of `block` elements
+/// using `copy_nonoverlapping`, and then the remainder. Regions could overlap, the result is
+/// the same as of `core::ptr::copy`.
+///
+/// The block is clamped to `[1, |dst - src|]`, so the source and the destination of a block
+/// never overlap. See [`suggested_block`] for a cache-friendly default.
+///
+/// ## Safety
+///
+/// * The region `[src, src + count)` must be valid for reading;
+/// * the region `[dst, dst + count)` must be valid for writing.
+///
+/// ## Example
+///
+/// ```text
+///            dst               src   count = 5, block = 2
+/// [ 1  2  3: 4  5  6  7  8  9*10 11 12 13 14 15]  // copy block(2)
+/// [ 1  .  3 10 11  6  .  .  9 10  .  .  .  . 15]  // copy block
+/// [ 1  .  3 10 11 12 13  8  9 10  .  .  .  . 15]  // copy rem(1)
+/// [ 1  .  3:10 ~~~~~~~~ 14  9*10  .  .  .  . 15]
+/// ```
+pub unsafe fn block_copy_sized<T>(src: *const T, dst: *mut T, count: usize, block: usize) {
+    let distance = dst.offset_from(src).unsigned_abs();
 
-        let rounds = count / block_size + 1;
-        let rem = count % block_size;
+    if distance == 0 {
+        return;
+    }
 
-        if src < dst {
-            s = src.add(count);
-            d = dst.add(count);
+    let block = cmp::max(1, cmp::min(block, distance));
 
-            for _ in 1..rounds {
-                s = s.sub(block_size);
-                d = d.sub(block_size);
+    let rounds = count / block;
+    let rem = count % block;
 
-                copy_nonoverlapping(s, d, block_size);
-            }
+    if src < dst {
+        let mut s = src.add(count);
+        let mut d = dst.add(count);
 
-            s = s.sub(rem);
-            d = d.sub(rem);
-            copy_nonoverlapping(s, d, rem);
-        } else {
-            for _ in 1..rounds {
-                copy_nonoverlapping(s, d, block_size);
+        for _ in 0..rounds {
+            s = s.sub(block);
+            d = d.sub(block);
 
-                s = s.add(block_size);
-                d = d.add(block_size);
-            }
+            copy_nonoverlapping(s, d, block);
+        }
 
-            s = s.add(1);
-            d = d.add(1);
-            copy_nonoverlapping(s, d, rem);
+        copy_nonoverlapping(src, dst, rem);
+    } else {
+        let mut s = src;
+        let mut d = dst;
+
+        for _ in 0..rounds {
+            copy_nonoverlapping(s, d, block);
+
+            s = s.add(block);
+            d = d.add(block);
         }
+
+        copy_nonoverlapping(s, d, rem);
+    }
+}
+
+/// Returns a block size for [`block_copy_sized`] of about one cache line (64 bytes), but not
+/// less than one element.
+#[inline(always)]
+pub const fn suggested_block<T>() -> usize {
+    const CACHE_LINE: usize = 64;
+
+    if size_of::<T>() == 0 || size_of::<T>() >= CACHE_LINE {
+        1
+    } else {
+        CACHE_LINE / size_of::<T>()
     }
 }
 
 /// # Shift left
 ///
 /// Shift region `[mid, mid + count)` to `[mid - left, mid - left + count)`
-/// using element-by-element copy (left-to-right), byte_copy or std::ptr::copy.
+/// using element-by-element copy (left-to-right), byte_copy or core::ptr::copy.
 ///
 /// ## Safety
 ///
@@ -234,21 +611,13 @@ pub unsafe fn block_copy<T>(src: *const T, dst: *mut T, count: usize) {
 /// [ 1  2 :4 *5 ~~~~~~~~~~~ 10 10 11  .  .  . 15]
 /// ```
 pub unsafe fn shift_left<T>(left: usize, mid: *mut T, count: usize) {
-    let start = mid.sub(left);
-
-    if size_of::<T>() == size_of::<usize>() && count >= 15 {
-        byte_copy(mid, start, count);
-    } else if size_of::<T>() < 15 * size_of::<usize>() {
-        copy(mid, start, count);
-    } else {
-        ptr::copy(mid, start, count);
-    }
+    shift_by(mid, count, -(left as isize));
 }
 
 /// # Shift right
 ///
 /// Shift region `[mid - count, mid)` to `[mid - count + right, mid + right)`
-/// using element-by-element copy (right-to-left), byte_copy or std::ptr::copy.
+/// using element-by-element copy (right-to-left), byte_copy or core::ptr::copy.
 ///
 /// ## Safety
 ///
@@ -264,17 +633,83 @@ pub unsafe fn shift_left<T>(left: usize, mid: *mut T, count: usize) {
 /// [ 1  2  3 *4 :4 ~~~~~~~~~~~~~~ 10 12  .  . 15]
 /// ```
 pub unsafe fn shift_right<T>(count: usize, mid: *mut T, right: usize) {
-    let start = mid.sub(count);
+    shift_by(mid.sub(count), count, right as isize);
+}
 
-    if size_of::<T>() == size_of::<usize>() && count >= 200 {
-        byte_copy(start, start.add(right), count);
-    } else if size_of::<T>() < 10 * size_of::<usize>() {
-        copy(start, start.add(right), count);
-    } else {
-        byte_copy(start, start.add(right), count);
+/// # Shift by
+///
+/// Shift region `[src, src + count)` to `[src + offset, src + offset + count)`.
+///
+/// Negative `offset` shifts left using element-by-element copy (left-to-right), byte_copy or
+/// core::ptr::copy, positive `offset` shifts right using element-by-element copy
+/// (right-to-left) or byte_copy. The choice depends on `size_of::<T>()` and `count`, as
+/// benchmarked for [`shift_left`] and [`shift_right`].
+///
+/// All of the strategies move elements bitwise, so the choice affects speed only: e.g.
+/// `Box<dyn Trait>` (two `usize`s) is always shifted element by element, without drops.
+///
+/// ## Safety
+///
+/// * The region `[src         , src          + count)` must be valid for reading;
+/// * the region `[src + offset, src + offset + count)` must be valid for writing.
+///
+/// ## Example
+///
+/// ```text
+///            dst      src   count = 7
+/// [ 1  2  3: 4  5  6* 7  8  9 10 11 12 13 14 15]  // shift by -3
+///                     └─────────────────┘
+/// [ 1  2  3: 7 ~~~~~~~~~~~~~~ 13 11 12 13 14 15]
+/// ```
+pub unsafe fn shift_by<T>(src: *mut T, count: usize, offset: isize) {
+    let dst = src.offset(offset);
+
+    if offset < 0 {
+        if size_of::<T>() == size_of::<usize>() && count >= BYTE_COPY_LEFT_COUNT {
+            byte_copy(src, dst, count);
+        } else if prefers_ptr_copy::<T>() {
+            ptr::copy(src, dst, count);
+        } else {
+            copy(src, dst, count);
+        }
+    } else if offset > 0 {
+        if size_of::<T>() == size_of::<usize>() && count >= BYTE_COPY_RIGHT_COUNT {
+            byte_copy(src, dst, count);
+        } else if size_of::<T>() < COPY_ELEMENTWISE_RIGHT_LIMIT * size_of::<usize>() {
+            copy(src, dst, count);
+        } else {
+            byte_copy(src, dst, count);
+        }
     }
 }
 
+/// # Swap by reference
+///
+/// Swaps elements `p.add(x)` and `p.add(y)` with `mem::swap` on references to them, instead of
+/// `ptr::swap`. Depending on `T` either may be faster: see the `Swap pair` benchmark.
+///
+/// ## Safety
+///
+/// The specified elements must be valid for reading and writing, and `x != y`: two mutable
+/// references to the same element can't coexist.
+///
+/// ## Example
+///
+/// ```text
+///            x                 y
+/// [ 1  2  3  4  5  6  7  8  9 10 11 12 13 14 15]  // swap
+///            └─────────────────┘
+/// [ 1  .  3 10  5  .  .  .  9  4 11  .  .  . 15]
+/// ```
+#[inline(always)]
+pub unsafe fn swap_ref<T>(p: *mut T, x: usize, y: usize) {
+    debug_assert!(x != y, "swap_ref: x = y = {x}");
+
+    let (x, y) = (&mut *p.add(x), &mut *p.add(y));
+
+    core::mem::swap(x, y);
+}
+
 /// # Swap forward
 ///
 /// Swaps regions `[x, x+count)` and `[y, y+count)` moving right,
@@ -327,7 +762,7 @@ pub unsafe fn swap_forward<T>(x: *mut T, y: *mut T, count: usize) {
         // SAFETY: By precondition, `i` is in-bounds because it's below `count`
         let y = unsafe { &mut *y.add(i) };
 
-        std::mem::swap(&mut *x, &mut *y);
+        core::mem::swap(&mut *x, &mut *y);
     }
 }
 
@@ -384,39 +819,165 @@ pub unsafe fn swap_backward<T>(x: *mut T, y: *mut T, count: usize) {
         // SAFETY: By precondition, `i` is in-bounds because it's below `count`
         let y = unsafe { &mut *y.sub(i) };
 
-        std::mem::swap(&mut *x, &mut *y);
+        core::mem::swap(&mut *x, &mut *y);
+    }
+}
+
+/// # Swap halves
+///
+/// Swaps the non-overlapping regions `[a, a+count)` and `[b, b+count)`, like the equal sides
+/// (`left == right`) of a rotation. All the equal-halves branches of the rotations go through
+/// it.
+///
+/// With the `simd` feature `u8`, `u16` and `u32` are swapped as `u8x32` lanes with a scalar tail.
+/// Any other `T` (which may contain padding bytes) and builds without the feature use
+/// `ptr::swap_nonoverlapping`.
+///
+/// ## Safety
+///
+/// * Both regions must be valid for reading and writing;
+/// * the regions must not overlap.
+///
+/// ## Example
+///
+/// ```text
+///            a        b     count = 3
+/// [ 1  2  3 :4  5  6* 7  8  9 10 11 12 13 14 15]  // swap halves
+///            └─────┴/\┴─────┘
+///            ┌─────┬~~┬─────┐
+/// [ 1  .  3 :7  8  9* 4  5  6 10  .  .  .  . 15]
+/// ```
+#[inline(always)]
+pub unsafe fn swap_halves<T>(a: *mut T, b: *mut T, count: usize) {
+    #[cfg(feature = "simd")]
+    <T as Lanes>::swap_halves(a, b, count);
+
+    #[cfg(not(feature = "simd"))]
+    ptr::swap_nonoverlapping(a, b, count);
+}
+
+/// Swaps `[a, a+bytes)` and `[b, b+bytes)` as `u8x32` lanes, then the tail.
+#[cfg(feature = "simd")]
+#[inline(always)]
+unsafe fn simd_swap_bytes(a: *mut u8, b: *mut u8, bytes: usize) {
+    const LANES: usize = 32;
+
+    let mut done = 0;
+
+    while bytes - done >= LANES {
+        let x = a.add(done).cast::<[u8; LANES]>();
+        let y = b.add(done).cast::<[u8; LANES]>();
+
+        let vx = Simd::<u8, LANES>::from_array(x.read_unaligned());
+        let vy = Simd::<u8, LANES>::from_array(y.read_unaligned());
+
+        x.write_unaligned(vy.to_array());
+        y.write_unaligned(vx.to_array());
+
+        done += LANES;
     }
+
+    ptr::swap_nonoverlapping(a.add(done), b.add(done), bytes - done);
+}
+
+/// # Swap forward (blocks)
+///
+/// Swaps regions `[x, x+count)` and `[y, y+count)` moving right, `block` elements at a time
+/// using `ptr::swap_nonoverlapping`, and then the remainder.
+///
+/// Regions could overlap! The block is clamped to `|y - x|`, so the blocks never overlap and
+/// the result is the same as of [`swap_forward`].
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+///
+/// ## Example
+///
+/// ```text
+///            x        y     count = 7, block = 3
+/// [ 1  2  3 :4  5  6 *7  8  9 10 11 12 13 14 15]  // swap -->
+///            └─────┴──┴─────┘
+/// [ 1  .  3  7  8  9  4  5  6 10  .  .  .  . 15]
+///                     └─────┴──┴─────┘
+/// [ 1  .  3  7  .  9 10 11 12  4  5  6 13  . 15]
+///                              └──┘
+/// [ 1  .  3 :7  .  9*10  .  . 13  5  6  4 14 15]
+/// ```
+pub unsafe fn swap_forward_blocks<T>(x: *mut T, y: *mut T, count: usize, block: usize) {
+    let distance = y.offset_from(x).unsigned_abs();
+
+    if distance == 0 {
+        return;
+    }
+
+    let block = cmp::max(1, cmp::min(block, distance));
+    let mut i = 0;
+
+    while i + block <= count {
+        ptr::swap_nonoverlapping(x.add(i), y.add(i), block);
+        i += block;
+    }
+
+    ptr::swap_nonoverlapping(x.add(i), y.add(i), count - i);
+}
+
+/// # Swap backward (blocks)
+///
+/// Swaps regions `[x, x+count)` and `[y, y+count)` moving left, `block` elements at a time
+/// using `ptr::swap_nonoverlapping`, and then the remainder.
+///
+/// Regions could overlap! The block is clamped to `|y - x|`, so the blocks never overlap and
+/// the result is the same as of [`swap_backward`].
+///
+/// ## Safety
+///
+/// The specified range must be valid for reading and writing.
+///
+/// ## Example
+///
+/// ```text
+///                        x        y   count = 5, block = 3
+/// [ 1  2  3  4  5  6  7  8  9 10 11 12 13 14 15]  // swap <--
+///                              └─────┴──┴─────┘
+/// [ 1  .  .  .  .  .  .  .  9 13 14 15 10 11 12]
+///                        └──┴─────┴──┘
+/// [ 1  .  .  .  .  .  7 14 15 13  8  9 10 11 12]
+/// ```
+pub unsafe fn swap_backward_blocks<T>(x: *mut T, y: *mut T, count: usize, block: usize) {
+    let distance = y.offset_from(x).unsigned_abs();
+
+    if distance == 0 {
+        return;
+    }
+
+    let block = cmp::max(1, cmp::min(block, distance));
+    let mut i = count;
+
+    while i >= block {
+        i -= block;
+        ptr::swap_nonoverlapping(x.add(i), y.add(i), block);
+    }
+
+    ptr::swap_nonoverlapping(x, y, i);
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::test_utils::seq;
     use crate::*;
 
     fn seq_multi<const N: usize>(size: usize) -> Vec<[usize; N]> {
-        let mut v = vec![[0; N]; size];
-        for i in 0..size {
-            v[i] = [i + 1; N];
-        }
-        v
-    }
-
-    fn seq(size: usize) -> Vec<usize> {
-        let mut v = vec![0; size];
-        for i in 0..size {
-            v[i] = i + 1;
-        }
-        v
+        (1..=size).map(|i| [i; N]).collect()
     }
 
     fn prepare(len: usize, x: usize, y: usize) -> (Vec<usize>, (*mut usize, *mut usize)) {
         let mut v = seq(len);
 
-        unsafe {
-            let x = &v[..].as_mut_ptr().add(x - 1);
-            let y = &v[..].as_mut_ptr().add(y - 1);
+        let x = unsafe { v.as_mut_ptr().add(x - 1) };
+        let y = unsafe { v.as_mut_ptr().add(y - 1) };
 
-            (v, (x.clone(), y.clone()))
-        }
+        (v, (x, y))
     }
 
     #[test]
@@ -488,26 +1049,26 @@ mod tests {
     #[test]
     fn shift_left_correct() {
         let mut v = seq(15);
-        let mut mid = *unsafe { &v[..].as_mut_ptr().add(3) };
+        let mut mid = unsafe { v.as_mut_ptr().add(3) };
 
         unsafe { shift_left(1, mid, 7) };
 
         assert_eq!(v, vec![1, 2, 4, 5, 6, 7, 8, 9, 10, 10, 11, 12, 13, 14, 15]);
 
-        mid = *unsafe { &v[..].as_mut_ptr().add(2) };
+        mid = unsafe { v.as_mut_ptr().add(2) };
 
         unsafe { shift_left(1, mid, 7) };
 
         assert_eq!(v, vec![1, 4, 5, 6, 7, 8, 9, 10, 10, 10, 11, 12, 13, 14, 15]);
 
         v = seq(15);
-        let mut mid = *unsafe { &v[..].as_mut_ptr().add(3) };
+        let mut mid = unsafe { v.as_mut_ptr().add(3) };
 
         unsafe { shift_left(1, mid, 7) };
 
         assert_eq!(v, vec![1, 2, 4, 5, 6, 7, 8, 9, 10, 10, 11, 12, 13, 14, 15]);
 
-        mid = *unsafe { &v[..].as_mut_ptr().add(2) };
+        mid = unsafe { v.as_mut_ptr().add(2) };
 
         unsafe { shift_left(1, mid, 7) };
 
@@ -517,13 +1078,13 @@ mod tests {
     #[test]
     fn shift_right_correct() {
         let mut v = seq(15);
-        let mut src = *unsafe { &v[..].as_mut_ptr().add(3) };
+        let mut src = unsafe { v.as_mut_ptr().add(3) };
 
         unsafe { shift_right(7, src.add(7), 1) };
 
         assert_eq!(v, vec![1, 2, 3, 4, 4, 5, 6, 7, 8, 9, 10, 12, 13, 14, 15]);
 
-        src = *unsafe { &v[..].as_mut_ptr().add(4) };
+        src = unsafe { v.as_mut_ptr().add(4) };
 
         unsafe { shift_right(7, src.add(7), 1) };
 
@@ -533,14 +1094,14 @@ mod tests {
     #[test]
     fn shift_correct() {
         let mut v = seq_multi::<20>(15);
-        let mut src = *unsafe { &v[..].as_mut_ptr().add(1) };
+        let mut src = unsafe { v.as_mut_ptr().add(1) };
 
         unsafe { shift_left(1, src, 14) };
 
         assert_eq!(v[0..13], seq_multi::<20>(14)[1..14]);
 
         v = seq_multi::<20>(15);
-        src = *&v[..].as_mut_ptr();
+        src = v.as_mut_ptr();
 
         unsafe { shift_right(14, src.add(14), 1) };
         assert_eq!(v[1..14], seq_multi::<20>(14)[0..13]);
@@ -548,6 +1109,17 @@ mod tests {
 
     // Swaps:
 
+    #[test]
+    fn swap_ref_correct() {
+        let mut v = [1, 2, 3];
+
+        unsafe { swap_ref(v.as_mut_ptr(), 0, 2) };
+        assert_eq!(v, [3, 2, 1]);
+
+        unsafe { swap_ref(v.as_mut_ptr(), 1, 0) };
+        assert_eq!(v, [2, 3, 1]);
+    }
+
     #[test]
     fn swap_forward_correct() {
         let (v, (x, y)) = prepare(15, 4, 7);
@@ -581,4 +1153,390 @@ mod tests {
         let s = vec![15, 9, 10, 11, 12, 13, 14, 1, 2, 3, 4, 5, 6, 7, 8];
         assert_eq!(v, s);
     }
+
+    #[test]
+    fn is_zst_correct() {
+        assert!(is_zst::<()>());
+        assert!(is_zst::<[usize; 0]>());
+        assert!(!is_zst::<u8>());
+        assert!(!is_zst::<[usize; 4]>());
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn is_zst_nightly_matches_size_of() {
+        use core::mem::SizedTypeProperties;
+
+        assert_eq!(<()>::IS_ZST, core::mem::size_of::<()>() == 0);
+        assert_eq!(<usize>::IS_ZST, core::mem::size_of::<usize>() == 0);
+        assert_eq!(is_zst::<[u8; 0]>(), core::mem::size_of::<[u8; 0]>() == 0);
+    }
+
+    fn reverse_case<T: Copy + PartialEq + core::fmt::Debug>(f: impl Fn(usize) -> T) {
+        for len in (0..=100).chain([255, 256, 257, 1000]) {
+            let mut v: Vec<T> = (0..len).map(&f).collect();
+            let mut s = v.clone();
+
+            s.reverse();
+            unsafe { reverse_slice(v.as_mut_ptr(), len) };
+
+            assert_eq!(v, s, "len = {len}");
+        }
+    }
+
+    #[test]
+    fn reverse_slice_widths_correct() {
+        reverse_case(|i| i as u8);
+        reverse_case(|i| i as u16);
+        reverse_case(|i| i as u32);
+        reverse_case(|i| [i as u8, (i >> 8) as u8]);
+        reverse_case(|i| [i as u8; 3]);
+        reverse_case(|i| i);
+    }
+
+    fn shift_by_case<const N: usize>() {
+        for count in [0, 1, 2, 5, 14, 15, 16, 40, 199, 200, 250] {
+            for offset in [-40, -20, -7, -3, -1, 0, 1, 3, 7, 20, 40] {
+                let mut v = seq_multi::<N>(count + 80);
+                let mut s = seq_multi::<N>(count + 80);
+
+                unsafe {
+                    let src = v.as_mut_ptr().add(40);
+                    shift_by(src, count, offset);
+
+                    let src = s.as_mut_ptr().add(40);
+                    ptr::copy(src, src.offset(offset), count);
+                }
+
+                assert_eq!(v, s, "count = {count}, offset = {offset}, N = {N}");
+            }
+        }
+    }
+
+    #[test]
+    fn shift_by_correct() {
+        shift_by_case::<1>();
+        shift_by_case::<2>();
+        shift_by_case::<12>();
+        shift_by_case::<20>();
+    }
+
+    #[test]
+    fn swap_blocks_correct() {
+        for count in [0, 1, 2, 3, 7, 15, 40] {
+            for distance in [1, 2, 3, 5, 8, 40, 50] {
+                for block in [0, 1, 2, 3, 4, 16, 100] {
+                    let mut v = seq(count + distance);
+                    let mut s = seq(count + distance);
+
+                    unsafe {
+                        let (x, y) = (v.as_mut_ptr(), v.as_mut_ptr().add(distance));
+                        swap_forward_blocks(x, y, count, block);
+
+                        let (x, y) = (s.as_mut_ptr(), s.as_mut_ptr().add(distance));
+                        swap_forward(x, y, count);
+                    }
+
+                    assert_eq!(v, s, "forward: {count}, {distance}, {block}");
+
+                    unsafe {
+                        let (x, y) = (v.as_mut_ptr(), v.as_mut_ptr().add(distance));
+                        swap_backward_blocks(x, y, count, block);
+
+                        let (x, y) = (s.as_mut_ptr(), s.as_mut_ptr().add(distance));
+                        swap_backward(x, y, count);
+                    }
+
+                    assert_eq!(v, s, "backward: {count}, {distance}, {block}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reverse_blocks_correct() {
+        for block_size in 1..=4 {
+            for count in 0..=10 {
+                let mut v = seq(count * block_size + 2);
+                let mut s = v.clone();
+
+                let reversed: Vec<usize> = s[1..=count * block_size]
+                    .chunks(block_size)
+                    .rev()
+                    .flatten()
+                    .copied()
+                    .collect();
+                s[1..=count * block_size].copy_from_slice(&reversed);
+
+                unsafe { reverse_blocks(v.as_mut_ptr().add(1), count, block_size) };
+
+                assert_eq!(v, s, "count = {count}, block_size = {block_size}");
+            }
+        }
+    }
+
+    #[test]
+    fn max_rotation_len_correct() {
+        assert_eq!(max_rotation_len::<()>(), usize::MAX);
+        assert_eq!(max_rotation_len::<u8>(), isize::MAX as usize);
+        assert_eq!(
+            max_rotation_len::<[u8; 1 << 20]>(),
+            isize::MAX as usize >> 20
+        );
+
+        debug_check_len::<u8>(isize::MAX as usize - 1, 1, "test");
+        debug_check_len::<()>(usize::MAX, 0, "test");
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "block_copy: left + right")]
+    fn block_copy_too_long() {
+        let mut v = [0u8; 4];
+        let src = v.as_ptr();
+
+        // a `block_copy` spanning more than `isize::MAX` bytes
+        unsafe { block_copy(src, v.as_mut_ptr().add(1), isize::MAX as usize) };
+    }
+
+    #[test]
+    fn prefers_ptr_copy_correct() {
+        fn check<T>() {
+            assert_eq!(
+                prefers_ptr_copy::<T>(),
+                size_of::<T>() >= COPY_ELEMENTWISE_LIMIT * size_of::<usize>()
+            );
+        }
+
+        check::<u8>();
+        check::<usize>();
+        check::<[usize; COPY_ELEMENTWISE_LIMIT - 1]>();
+        check::<[usize; COPY_ELEMENTWISE_LIMIT]>();
+        check::<[usize; 100]>();
+
+        assert!(!prefers_ptr_copy::<[usize; COPY_ELEMENTWISE_LIMIT - 1]>());
+        assert!(prefers_ptr_copy::<[usize; COPY_ELEMENTWISE_LIMIT]>());
+    }
+
+    #[test]
+    fn block_copy_sized_correct() {
+        let len = 40;
+
+        for count in [0, 1, 2, 5, 7, 16, 20] {
+            for (x, y) in [(0, 1), (0, 3), (3, 0), (5, 12), (12, 5), (0, 20), (20, 0)] {
+                for block in [0, 1, 2, 3, 8, 100] {
+                    let mut v = seq(len);
+                    let mut s = seq(len);
+
+                    unsafe {
+                        block_copy_sized(v.as_ptr().add(x), v.as_mut_ptr().add(y), count, block);
+                        ptr::copy(s.as_ptr().add(x), s.as_mut_ptr().add(y), count);
+                    }
+
+                    assert_eq!(v, s, "{x} -> {y}, count = {count}, block = {block}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn block_copy_overlapping_correct() {
+        let len = 40;
+
+        for count in [0, 1, 2, 5, 7, 16, 20] {
+            for (x, y) in [(0, 1), (0, 3), (3, 0), (5, 12), (12, 5), (0, 20), (20, 0)] {
+                let mut v = seq(len);
+                let mut s = seq(len);
+
+                unsafe {
+                    block_copy(v.as_ptr().add(x), v.as_mut_ptr().add(y), count);
+                    ptr::copy(s.as_ptr().add(x), s.as_mut_ptr().add(y), count);
+                }
+
+                assert_eq!(v, s, "{x} -> {y}, count = {count}");
+            }
+        }
+    }
+
+    #[test]
+    fn suggested_block_correct() {
+        assert_eq!(suggested_block::<u8>(), 64);
+        assert_eq!(suggested_block::<u64>(), 8);
+        assert_eq!(suggested_block::<[u8; 64]>(), 1);
+        assert_eq!(suggested_block::<[u8; 100]>(), 1);
+        assert_eq!(suggested_block::<()>(), 1);
+    }
+
+    #[test]
+    fn block_copy_sweep_correct() {
+        for distance in 1..=8 {
+            for count in 1..=4 * distance {
+                let len = count + distance;
+
+                for (x, y) in [(0, distance), (distance, 0)] {
+                    let mut v = seq(len);
+                    let mut s = seq(len);
+
+                    unsafe {
+                        block_copy(v.as_ptr().add(x), v.as_mut_ptr().add(y), count);
+                        ptr::copy(s.as_ptr().add(x), s.as_mut_ptr().add(y), count);
+                    }
+
+                    assert_eq!(v, s, "{x} -> {y}, count = {count}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn copy_overlapping_correct() {
+        for offset in -8isize..=8 {
+            for count in 0..=20 {
+                let len = count + 8;
+                let x = if offset < 0 { offset.unsigned_abs() } else { 0 };
+                let y = x.wrapping_add_signed(offset);
+
+                let mut v = seq(len);
+                let mut s = seq(len);
+
+                unsafe {
+                    copy_overlapping(v.as_ptr().add(x), v.as_mut_ptr().add(y), count);
+                    ptr::copy(s.as_ptr().add(x), s.as_mut_ptr().add(y), count);
+                }
+
+                assert_eq!(v, s, "offset = {offset}, count = {count}");
+            }
+        }
+    }
+
+    #[test]
+    fn copy_auto_correct() {
+        fn check<const N: usize>() {
+            for offset in -20isize..=20 {
+                for count in 0..=20 {
+                    let len = count + 20;
+                    let x = if offset < 0 { offset.unsigned_abs() } else { 0 };
+                    let y = x.wrapping_add_signed(offset);
+
+                    let mut v = seq_multi::<N>(len);
+                    let mut s = seq_multi::<N>(len);
+
+                    unsafe {
+                        copy_auto(v.as_ptr().add(x), v.as_mut_ptr().add(y), count);
+                        ptr::copy(s.as_ptr().add(x), s.as_mut_ptr().add(y), count);
+                    }
+
+                    assert_eq!(v, s, "N = {N}, offset = {offset}, count = {count}");
+                }
+            }
+        }
+
+        check::<1>();
+        check::<3>();
+        check::<{ COPY_ELEMENTWISE_LIMIT }>();
+    }
+
+    #[test]
+    fn copy_smart_correct() {
+        fn check<T: Copy + PartialEq + core::fmt::Debug>(f: impl Fn(usize) -> T) {
+            for offset in -20isize..=20 {
+                for count in 0..=20 {
+                    let len = count + 20;
+                    let x = if offset < 0 { offset.unsigned_abs() } else { 0 };
+                    let y = x.wrapping_add_signed(offset);
+
+                    let mut v: Vec<T> = (0..len).map(&f).collect();
+                    let mut s = v.clone();
+
+                    unsafe {
+                        copy_smart(v.as_ptr().add(x), v.as_mut_ptr().add(y), count);
+                        ptr::copy(s.as_ptr().add(x), s.as_mut_ptr().add(y), count);
+                    }
+
+                    assert_eq!(v, s, "offset = {offset}, count = {count}");
+                }
+            }
+        }
+
+        check(|i| i as u8);
+        check(|i| [i as u8, (i >> 8) as u8, 3]);
+        check(|i| i as u64);
+        check(|i| [i, i + 1, i + 2, i + 3]);
+    }
+
+    #[test]
+    fn reverse_slice_by_correct() {
+        use core::cell::Cell;
+
+        for count in (0..=40).chain([100, 1001]) {
+            let mut v: Vec<(usize, u8)> = (0..count).map(|i| (i, i as u8)).collect();
+            let mut s = v.clone();
+            let calls = Cell::new(0);
+
+            // field-wise swap
+            unsafe {
+                reverse_slice_by(v.as_mut_ptr(), count, |a, b| {
+                    calls.set(calls.get() + 1);
+                    ptr::swap(ptr::addr_of_mut!((*a).0), ptr::addr_of_mut!((*b).0));
+                    ptr::swap(ptr::addr_of_mut!((*a).1), ptr::addr_of_mut!((*b).1));
+                });
+            }
+            s.reverse();
+
+            assert_eq!(v, s, "count = {count}");
+            assert_eq!(calls.get(), count / 2, "count = {count}");
+        }
+    }
+
+    #[test]
+    fn swap_halves_correct() {
+        fn check<const N: usize>() {
+            for count in (0..=40).chain([100, 1000]) {
+                for gap in [0, 1, 5] {
+                    let len = 2 * count + gap;
+
+                    let mut v = seq_multi::<N>(len);
+                    let mut s = seq_multi::<N>(len);
+
+                    unsafe {
+                        swap_halves(v.as_mut_ptr(), v.as_mut_ptr().add(count + gap), count);
+                        ptr::swap_nonoverlapping(
+                            s.as_mut_ptr(),
+                            s.as_mut_ptr().add(count + gap),
+                            count,
+                        );
+                    }
+
+                    assert_eq!(v, s, "N = {N}, count = {count}, gap = {gap}");
+                }
+            }
+        }
+
+        check::<1>();
+        check::<3>();
+        check::<10>();
+    }
+
+    #[test]
+    fn swap_halves_lanes_correct() {
+        fn check<T: Copy + PartialEq + core::fmt::Debug>(f: impl Fn(usize) -> T) {
+            // unaligned, longer than a few lanes
+            for count in 0..=100 {
+                let mut v: Vec<T> = (0..256).map(&f).collect();
+                let mut s = v.clone();
+
+                unsafe {
+                    swap_halves(v.as_mut_ptr().add(1), v.as_mut_ptr().add(128), count);
+                    ptr::swap_nonoverlapping(s.as_mut_ptr().add(1), s.as_mut_ptr().add(128), count);
+                }
+
+                assert_eq!(v, s, "count = {count}");
+            }
+        }
+
+        check(|i| i as u8);
+        check(|i| i as u16);
+        check(|i| i as u32);
+        check(|i| [i as u8; 3]);
+    }
 }